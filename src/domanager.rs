@@ -1,15 +1,157 @@
+use std::sync::{Arc, Mutex};
+
+use url::Url;
+
+use ratelimit::{Policy, RateLimit, Throttle};
 use response;
 use request::RequestBuilder;
+use transport::{HyperTransport, Transport};
+use Error;
 
-/// The main structure through which all calls are made. This holds a slice of the AUTH TOKEN
+/// The URL all requests are made against unless configured otherwise
+const DEFAULT_BASE_URL: &'static str = "https://api.digitalocean.com/v2";
+
+/// The main structure through which all calls are made. This holds a slice of the AUTH TOKEN,
+/// the `Transport` used to send requests, the base URL of the API and the latest known rate
+/// limit (which is shared between clones)
 #[derive(Clone)]
 pub struct DoManager<'t> {
     auth: &'t str,
+    transport: Arc<Transport>,
+    base: Url,
+    rate_limit: Arc<Mutex<Option<RateLimit>>>,
+}
+
+/// Configures and builds a `DoManager`, see `DoManager::builder()`
+pub struct DoManagerBuilder<'t> {
+    auth: &'t str,
+    transport: Arc<Transport>,
+    base: String,
+    policy: Policy,
+}
+
+impl<'t> DoManagerBuilder<'t> {
+    /// Sets the AUTH TOKEN sent with every request
+    pub fn token(mut self, token: &'t str) -> DoManagerBuilder<'t> {
+        self.auth = token;
+        self
+    }
+
+    /// Sends all requests to `url` instead of `https://api.digitalocean.com/v2`, for instance to
+    /// go through a proxy, a staging environment, or a local stand-in such as
+    /// `doapi::testing::MockServer`
+    pub fn base_url<S>(mut self, url: S) -> DoManagerBuilder<'t>
+        where S: Into<String>
+    {
+        self.base = url.into();
+        self
+    }
+
+    /// Sends all requests through `transport` instead of the default `HyperTransport`
+    pub fn transport<T>(mut self, transport: T) -> DoManagerBuilder<'t>
+        where T: Transport + 'static
+    {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Sets how requests are throttled and retried, see `doapi::ratelimit::Policy`
+    pub fn policy(mut self, policy: Policy) -> DoManagerBuilder<'t> {
+        self.policy = policy;
+        self
+    }
+
+    /// Builds the `DoManager`, failing if the base URL is invalid
+    pub fn build(self) -> Result<DoManager<'t>, Error> {
+        let base = try!(Url::parse(&self.base[..]));
+        if base.cannot_be_a_base() {
+            return Err(Error::Url(::url::ParseError::RelativeUrlWithCannotBeABaseBase));
+        }
+        let rate_limit = Arc::new(Mutex::new(None));
+        Ok(DoManager {
+            auth: self.auth,
+            transport: Arc::new(Throttle::new(self.transport, self.policy, rate_limit.clone())),
+            base: base,
+            rate_limit: rate_limit,
+        })
+    }
 }
 
 impl<'t> DoManager<'t> {
     /// Creates a new instance of `DoManager` with a string slice of your AUTH TOKEN
-    pub fn with_token(token: &'t str) -> DoManager<'t> { DoManager { auth: token } }
+    pub fn with_token(token: &'t str) -> DoManager<'t> {
+        DoManager::with_transport(token, HyperTransport)
+    }
+
+    /// Creates a new instance of `DoManager` which sends all requests through `transport` instead
+    /// of the default `HyperTransport`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::transport::HyperTransport;
+    /// // Any type implementing doapi::transport::Transport may be used
+    /// let domgr = DoManager::with_transport("asfasdfasdf", HyperTransport);
+    /// ```
+    pub fn with_transport<T>(token: &'t str, transport: T) -> DoManager<'t>
+        where T: Transport + 'static
+    {
+        DoManager::builder()
+            .token(token)
+            .transport(transport)
+            .build()
+            .expect("the default base URL is valid")
+    }
+
+    /// Returns a `DoManagerBuilder` which can be used to configure the AUTH TOKEN, base URL,
+    /// `Transport` and rate limit `Policy` of a new `DoManager`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// let domgr = DoManager::builder()
+    ///                 .token("asfasdfasdf")
+    ///                 .base_url("https://do-proxy.internal.example.com/v2")
+    ///                 .build()
+    ///                 .unwrap();
+    /// ```
+    pub fn builder() -> DoManagerBuilder<'t> {
+        DoManagerBuilder {
+            auth: "",
+            transport: Arc::new(HyperTransport),
+            base: DEFAULT_BASE_URL.to_owned(),
+            policy: Policy::new(),
+        }
+    }
+
+    /// Returns the rate limit reported by DigitalOcean in the latest response, or `None` if no
+    /// request has been made yet
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// let _ = domgr.account().retrieve();
+    /// if let Some(rl) = domgr.rate_limit() {
+    ///     println!("{} of {} requests left", rl.remaining, rl.limit);
+    /// }
+    /// ```
+    pub fn rate_limit(&self) -> Option<RateLimit> { *self.rate_limit.lock().unwrap() }
+
+    /// Returns the URL of the endpoint at `segments` below the base URL, percent-encoding each
+    /// segment
+    fn endpoint(&self, segments: &[&str]) -> Url {
+        let mut url = self.base.clone();
+        url.path_segments_mut()
+           .expect("checked in DoManagerBuilder::build()")
+           .pop_if_empty()
+           .extend(segments);
+        url
+    }
 
     /// Returns a request that can be used to view account information.
     ///
@@ -26,7 +168,7 @@ impl<'t> DoManager<'t> {
     /// }
     /// ```
     pub fn account(&self) -> RequestBuilder<'t, response::Account> {
-        RequestBuilder::new(self.auth, "https://api.digitalocean.com/v2/account")
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["account"]))
     }
 
     /// Returns a request that can be used to list all regions
@@ -44,7 +186,7 @@ impl<'t> DoManager<'t> {
     /// }
     /// ```
     pub fn regions(&self) -> RequestBuilder<'t, response::Regions> {
-        RequestBuilder::new(self.auth, "https://api.digitalocean.com/v2/regions")
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["regions"]))
     }
 
     /// Returns a request that can be used to list all available sizes
@@ -62,7 +204,7 @@ impl<'t> DoManager<'t> {
     /// }
     /// ```
     pub fn sizes(&self) -> RequestBuilder<'t, response::Sizes> {
-        RequestBuilder::new(self.auth, "https://api.digitalocean.com/v2/sizes")
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["sizes"]))
     }
 
     /// Returns a request that can be used gain additional requests for a particular image
@@ -83,8 +225,7 @@ impl<'t> DoManager<'t> {
     /// }
     /// ```
     pub fn image(&self, id: &str) -> RequestBuilder<'t, response::Image> {
-        RequestBuilder::new(self.auth,
-                            format!("https://api.digitalocean.com/v2/images/{}", id))
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["images", id]))
     }
 
     /// Returns a request that can be used to view all available images, or actions on multiple
@@ -102,7 +243,7 @@ impl<'t> DoManager<'t> {
     /// }
     /// ```
     pub fn images(&self) -> RequestBuilder<'t, response::Images> {
-        RequestBuilder::new(self.auth, "https://api.digitalocean.com/v2/images")
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["images"]))
     }
 
     /// Returns a request that can be used to view all SSH keys or actions on multiple keys
@@ -120,7 +261,7 @@ impl<'t> DoManager<'t> {
     /// }
     /// ```
     pub fn ssh_keys(&self) -> RequestBuilder<'t, response::SshKeys> {
-        RequestBuilder::new(self.auth, "https://api.digitalocean.com/v2/account/keys")
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["account", "keys"]))
     }
 
     /// Returns a request that can be used to view a single SSH key, or actions that apply to only
@@ -143,7 +284,8 @@ impl<'t> DoManager<'t> {
     /// ```
     pub fn ssh_key(&self, id: &str) -> RequestBuilder<'t, response::SshKey> {
         RequestBuilder::new(self.auth,
-                            format!("https://api.digitalocean.com/v2/account/keys/{}", id))
+                            self.transport.clone(),
+                            self.endpoint(&["account", "keys", id]))
     }
 
 
@@ -165,8 +307,7 @@ impl<'t> DoManager<'t> {
     /// }
     /// ```
     pub fn droplet(&self, id: &str) -> RequestBuilder<'t, response::Droplet> {
-        RequestBuilder::new(self.auth,
-                            format!("https://api.digitalocean.com/v2/droplets/{}", id))
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["droplets", id]))
     }
 
     /// Returns a request that can be used to view all available droplets, or actions that apply to
@@ -185,7 +326,7 @@ impl<'t> DoManager<'t> {
     /// }
     /// ```
     pub fn droplets(&self) -> RequestBuilder<'t, response::Droplets> {
-        RequestBuilder::new(self.auth, "https://api.digitalocean.com/v2/droplets")
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["droplets"]))
     }
 
     /// Returns a request that can be used to view all domains, or actions that apply to multiple
@@ -203,7 +344,7 @@ impl<'t> DoManager<'t> {
     /// }
     /// ```
     pub fn domains(&self) -> RequestBuilder<'t, response::Domains> {
-        RequestBuilder::new(self.auth, "https://api.digitalocean.com/v2/domains")
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["domains"]))
     }
 
     /// Returns a request that can be used to view a single domain, or actions that apply to only
@@ -222,7 +363,654 @@ impl<'t> DoManager<'t> {
     /// }
     /// ```
     pub fn domain(&self, name: &str) -> RequestBuilder<'t, response::Domain> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["domains", name]))
+    }
+
+    /// Returns a request that can be used to view all block storage volumes, or create a new one
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.volumes()
+    ///            .region("nyc1")
+    ///            .retrieve() {
+    ///     Ok(_)  => println!("Success"),
+    ///     Err(_) => println!("Error")
+    /// }
+    /// ```
+    pub fn volumes(&self) -> RequestBuilder<'t, response::Volumes> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["volumes"]))
+    }
+
+    /// Returns a request that can be used to view a single block storage volume, or actions that
+    /// apply to only one volume
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.volume("7724db7c-e098-11e5-b522-000f53304e51")
+    ///            .attach(1234)
+    ///            .retrieve() {
+    ///     Ok(_)  => println!("Success"),
+    ///     Err(_) => println!("Error")
+    /// }
+    /// ```
+    pub fn volume(&self, id: &str) -> RequestBuilder<'t, response::Volume> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["volumes", id]))
+    }
+
+    /// Returns a request that can be used to view all floating IPs, or reserve a new one
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// // Reserves a new floating IP, assigned to droplet 1234
+    /// match domgr.floating_ips()
+    ///            .create_assigned(1234)
+    ///            .retrieve() {
+    ///     Ok(_)  => println!("Success"),
+    ///     Err(_) => println!("Error")
+    /// }
+    /// ```
+    pub fn floating_ips(&self) -> RequestBuilder<'t, response::FloatingIps> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["floating_ips"]))
+    }
+
+    /// Returns a request that can be used to view a single floating IP, or actions that apply to
+    /// only one floating IP
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.floating_ip("45.55.96.47")
+    ///            .assign(1234)
+    ///            .retrieve_and_wait() {
+    ///     Ok(_)  => println!("Success"),
+    ///     Err(_) => println!("Error")
+    /// }
+    /// ```
+    pub fn floating_ip(&self, addr: &str) -> RequestBuilder<'t, response::FloatingIp> {
+        RequestBuilder::new(self.auth,
+                            self.transport.clone(),
+                            self.endpoint(&["floating_ips", addr]))
+    }
+
+    /// Returns a request that can be used to view all load balancers, or create a new one
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::LoadBalancer;
+    /// # use doapi::response::ForwardingRule;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// let mut lb = LoadBalancer::new("example-lb-01",
+    ///                                "nyc3",
+    ///                                vec![ForwardingRule::new("http", 80, "http", 80)]);
+    /// lb.tag = Some("web".to_owned());
+    /// match domgr.load_balancers()
+    ///            .create(&lb)
+    ///            .retrieve() {
+    ///     Ok(_)  => println!("Success"),
+    ///     Err(_) => println!("Error")
+    /// }
+    /// ```
+    pub fn load_balancers(&self) -> RequestBuilder<'t, response::LoadBalancers> {
+        RequestBuilder::new(self.auth,
+                            self.transport.clone(),
+                            self.endpoint(&["load_balancers"]))
+    }
+
+    /// Returns a request that can be used to view, change or delete a single load balancer
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.load_balancer("4de7ac8b-495b-4884-9a69-1050c6793cd6")
+    ///            .add_droplets(&[3164446, 3164447])
+    ///            .retrieve() {
+    ///     Ok(_)  => println!("Success"),
+    ///     Err(_) => println!("Error")
+    /// }
+    /// ```
+    pub fn load_balancer(&self, id: &str) -> RequestBuilder<'t, response::LoadBalancer> {
+        RequestBuilder::new(self.auth,
+                            self.transport.clone(),
+                            self.endpoint(&["load_balancers", id]))
+    }
+
+    /// Returns a request that can be used to view all cloud firewalls, or create a new one
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::Firewall;
+    /// # use doapi::response::{InboundRule, OutboundRule, Targets};
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// let mut fw = Firewall::new("web");
+    /// fw.inbound_rules.push(InboundRule::new("tcp", "443", Targets::addresses(&["0.0.0.0/0"])));
+    /// fw.outbound_rules.push(OutboundRule::new("tcp", "0", Targets::addresses(&["0.0.0.0/0"])));
+    /// fw.tags = Some(vec!["web".to_owned()]);
+    /// match domgr.firewalls()
+    ///            .create(&fw)
+    ///            .retrieve() {
+    ///     Ok(_)  => println!("Success"),
+    ///     Err(_) => println!("Error")
+    /// }
+    /// ```
+    pub fn firewalls(&self) -> RequestBuilder<'t, response::Firewalls> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["firewalls"]))
+    }
+
+    /// Returns a request that can be used to view, change or delete a single cloud firewall
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.firewall("bb4b2611-3d72-467b-8602-280330ecd65c")
+    ///            .add_tags(&["frontend"])
+    ///            .retrieve() {
+    ///     Ok(_)  => println!("Success"),
+    ///     Err(_) => println!("Error")
+    /// }
+    /// ```
+    pub fn firewall(&self, id: &str) -> RequestBuilder<'t, response::Firewall> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["firewalls", id]))
+    }
+
+    /// Returns a request that can be used to list or create tags. Droplets with a given tag are
+    /// listed, and acted on in bulk, through `droplets().with_tag(..)`. Bulk actions are only
+    /// offered once a tag has been given.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.droplets()
+    ///            .with_tag("web")
+    ///            .power_off()
+    ///            .retrieve() {
+    ///     Ok(actions) => println!("Started {} actions", actions.len()),
+    ///     Err(_)      => println!("Error")
+    /// }
+    /// ```
+    pub fn tags(&self) -> RequestBuilder<'t, response::Tags> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["tags"]))
+    }
+
+    /// Returns a request that can be used to view or delete a single tag, or to tag and untag
+    /// resources
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::TagResource;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.tag("web")
+    ///            .tag_resources(&[TagResource::droplet("9569411")])
+    ///            .retrieve() {
+    ///     Ok(_)  => println!("Success"),
+    ///     Err(_) => println!("Error")
+    /// }
+    /// ```
+    pub fn tag(&self, name: &str) -> RequestBuilder<'t, response::Tag> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["tags", name]))
+    }
+
+    /// Returns a request that can be used to list or create VPCs. Droplets are placed into a VPC
+    /// by setting `vpc_uuid` when creating them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::Vpc;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// let mut vpc = Vpc::new("env.prod-vpc", "nyc1");
+    /// vpc.ip_range = Some("10.10.10.0/24".to_owned());
+    /// match domgr.vpcs()
+    ///            .create(&vpc)
+    ///            .retrieve() {
+    ///     Ok(vpc) => println!("VPC: {}", vpc),
+    ///     Err(_)  => println!("Error")
+    /// }
+    /// ```
+    pub fn vpcs(&self) -> RequestBuilder<'t, response::Vpcs> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["vpcs"]))
+    }
+
+    /// Returns a request that can be used to view, update or delete a single VPC, or to list
+    /// its members
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.vpc("5a4981aa-9653-4bd1-bef5-d6bff52042e4")
+    ///            .members()
+    ///            .resource_type("droplet")
+    ///            .retrieve() {
+    ///     Ok(members) => println!("{} droplets", members.len()),
+    ///     Err(_)      => println!("Error")
+    /// }
+    /// ```
+    pub fn vpc(&self, id: &str) -> RequestBuilder<'t, response::Vpc> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["vpcs", id]))
+    }
+
+    /// Returns a request that can be used to list or create projects
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::Project;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.projects()
+    ///            .create(&Project::new("billing", "Web Application"))
+    ///            .retrieve() {
+    ///     Ok(project) => println!("Project: {}", project),
+    ///     Err(_)      => println!("Error")
+    /// }
+    /// ```
+    pub fn projects(&self) -> RequestBuilder<'t, response::Projects> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["projects"]))
+    }
+
+    /// Returns a request that can be used to view, update or delete a single project, or to
+    /// list and assign its resources. Resources are assigned by URN, see `response::Urn`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::response::Urn;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// let droplet = domgr.droplet("1234").retrieve().unwrap();
+    /// match domgr.project("4e1bfbc3-dc3e-41f2-a18f-1b4d7ba71679")
+    ///            .assign_resources(&[&droplet.urn()[..]])
+    ///            .retrieve() {
+    ///     Ok(_)  => println!("Success"),
+    ///     Err(_) => println!("Error")
+    /// }
+    /// ```
+    pub fn project(&self, id: &str) -> RequestBuilder<'t, response::Project> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["projects", id]))
+    }
+
+    /// Returns a request for the default project, which new resources are assigned to when no
+    /// project is specified
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.default_project().retrieve() {
+    ///     Ok(project) => println!("Project: {}", project),
+    ///     Err(_)      => println!("Error")
+    /// }
+    /// ```
+    pub fn default_project(&self) -> RequestBuilder<'t, response::Project> {
+        self.project("default")
+    }
+
+    /// Returns a request that can be used to list or create TLS certificates. Custom
+    /// certificates are validated locally when building the `request::Certificate`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::Certificate;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// let cert = Certificate::lets_encrypt("web", &["example.com", "www.example.com"]);
+    /// match domgr.certificates()
+    ///            .create(&cert)
+    ///            .retrieve() {
+    ///     Ok(cert) => println!("Certificate: {}", cert),
+    ///     Err(_)   => println!("Error")
+    /// }
+    /// ```
+    pub fn certificates(&self) -> RequestBuilder<'t, response::Certificates> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["certificates"]))
+    }
+
+    /// Returns a request that can be used to view or delete a single TLS certificate
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.certificate("892071a0-bb95-49bc-8021-3afd67a210bf").retrieve() {
+    ///     Ok(cert) => println!("Certificate: {}", cert),
+    ///     Err(_)   => println!("Error")
+    /// }
+    /// ```
+    pub fn certificate(&self, id: &str) -> RequestBuilder<'t, response::Certificate> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["certificates", id]))
+    }
+
+    /// Returns a request that can be used to list or create CDN endpoints
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::CdnEndpoint;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// let mut endpoint = CdnEndpoint::new("static-images.nyc3.digitaloceanspaces.com");
+    /// endpoint.ttl = Some(600);
+    /// match domgr.cdn_endpoints()
+    ///            .create(&endpoint)
+    ///            .retrieve() {
+    ///     Ok(endpoint) => println!("Endpoint: {}", endpoint),
+    ///     Err(_)       => println!("Error")
+    /// }
+    /// ```
+    pub fn cdn_endpoints(&self) -> RequestBuilder<'t, response::CdnEndpoints> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["cdn", "endpoints"]))
+    }
+
+    /// Returns a request that can be used to view, update or delete a single CDN endpoint, or to
+    /// purge cached files from it. Paths may end in a wildcard (i.e. `assets/css/*`), and `*`
+    /// purges everything.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.cdn_endpoint("19f06b6a-3ace-4315-b086-499a0e521b76")
+    ///            .purge(&["assets/img/hero.png", "assets/css/*"])
+    ///            .retrieve() {
+    ///     Ok(_)  => println!("Success"),
+    ///     Err(_) => println!("Error")
+    /// }
+    /// ```
+    pub fn cdn_endpoint(&self, id: &str) -> RequestBuilder<'t, response::CdnEndpoint> {
+        RequestBuilder::new(self.auth,
+                            self.transport.clone(),
+                            self.endpoint(&["cdn", "endpoints", id]))
+    }
+
+    /// Returns a request that can be used to list or create managed database clusters
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::Database;
+    /// # use doapi::response::Engine;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// let db = Database::new("backend", Engine::Postgres, "db-s-2vcpu-4gb", "nyc3", 2);
+    /// match domgr.databases()
+    ///            .create(&db)
+    ///            .retrieve() {
+    ///     Ok(db) => println!("Connect to: {}", db.connection.uri),
+    ///     Err(_) => println!("Error")
+    /// }
+    /// ```
+    pub fn databases(&self) -> RequestBuilder<'t, response::Databases> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["databases"]))
+    }
+
+    /// Returns a request that can be used to view, resize, migrate or delete a single managed
+    /// database cluster, or to manage its firewall, users, databases, connection pools, replicas
+    /// and backups
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::response::TrustedSource;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.database("9cc10173-e9ea-4176-9dbc-a4cee4c4ff30")
+    ///            .set_firewall(&[TrustedSource::tag("backend")])
+    ///            .retrieve() {
+    ///     Ok(_)  => println!("Success"),
+    ///     Err(_) => println!("Error")
+    /// }
+    /// ```
+    pub fn database(&self, id: &str) -> RequestBuilder<'t, response::Database> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["databases", id]))
+    }
+
+    /// Returns a request that can be used to list or create Kubernetes clusters, or to list the
+    /// available regions, versions and node sizes
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::{KubernetesCluster, NodePool};
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// let cluster = KubernetesCluster::new("prod-cluster-01",
+    ///                                      "nyc1",
+    ///                                      "1.18.8-do.0",
+    ///                                      vec![NodePool::new("workers", "s-2vcpu-4gb", 3)]);
+    /// match domgr.kubernetes_clusters()
+    ///            .create(&cluster)
+    ///            .retrieve() {
+    ///     Ok(cluster) => println!("Cluster: {}", cluster),
+    ///     Err(_)      => println!("Error")
+    /// }
+    /// ```
+    pub fn kubernetes_clusters(&self) -> RequestBuilder<'t, response::KubernetesClusters> {
+        RequestBuilder::new(self.auth,
+                            self.transport.clone(),
+                            self.endpoint(&["kubernetes", "clusters"]))
+    }
+
+    /// Returns a request that can be used to view, update, upgrade or delete a single Kubernetes
+    /// cluster, to manage its node pools, or to retrieve its kubeconfig
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use std::io::Write;
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.kubernetes_cluster("bd5f5959-5e1e-4205-a714-a914373942af")
+    ///            .kubeconfig()
+    ///            .expiry_seconds(3600)
+    ///            .retrieve() {
+    ///     Ok(config) => {
+    ///         println!("API server: {}", config.server);
+    ///         File::create("kubeconfig.yaml").unwrap().write_all(config.raw.as_bytes()).unwrap();
+    ///     }
+    ///     Err(_) => println!("Error")
+    /// }
+    /// ```
+    pub fn kubernetes_cluster(&self, id: &str) -> RequestBuilder<'t, response::KubernetesCluster> {
+        RequestBuilder::new(self.auth,
+                            self.transport.clone(),
+                            self.endpoint(&["kubernetes", "clusters", id]))
+    }
+
+    /// Returns a request that can be used to view, create or delete the account's container
+    /// registry, to manage its repositories and garbage collection, or to generate docker
+    /// credentials for it
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.registry()
+    ///            .docker_credentials()
+    ///            .read_write(true)
+    ///            .expiry_seconds(3600)
+    ///            .retrieve() {
+    ///     Ok(creds) => println!("{}", creds.raw),
+    ///     Err(_)    => println!("Error")
+    /// }
+    /// for tag in domgr.registry().repository("example", "app").tags().retrieve().unwrap() {
+    ///     println!("{}", tag);
+    /// }
+    /// ```
+    pub fn registry(&self) -> RequestBuilder<'t, response::Registry> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["registry"]))
+    }
+
+    /// Returns a request that can be used to query the metrics of a droplet over a time range
+    /// (in UNIX epoch seconds), or to view, create, update or delete alert policies
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::{AlertPolicy, DropletMetric};
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// let droplet = domgr.droplet("1234").retrieve().unwrap();
+    /// let cpu = domgr.monitoring()
+    ///                .droplet_metric(DropletMetric::Cpu, 1234, 1600000000, 1600003600)
+    ///                .retrieve()
+    ///                .unwrap();
+    /// for series in cpu.result {
+    ///     println!("{:?}: {:?}", series.metric.get("mode"), series.points());
+    /// }
+    /// let policy = AlertPolicy::new("v1/insights/droplet/cpu",
+    ///                               "High CPU",
+    ///                               "GreaterThan",
+    ///                               80.0,
+    ///                               "5m")
+    ///                  .droplets(&[&droplet]);
+    /// match domgr.monitoring().alert_policies().create(&policy).retrieve() {
+    ///     Ok(policy) => println!("Policy: {}", policy),
+    ///     Err(e)     => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn monitoring(&self) -> RequestBuilder<'t, response::Monitoring> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["monitoring"]))
+    }
+
+    /// Returns a request that can be used to list or create App Platform apps, or to validate
+    /// an app spec
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::response::{AppService, AppSpec, GitHubSource};
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// let mut spec = AppSpec::new("web");
+    /// spec.services = Some(vec![AppService::new("api", GitHubSource::new("me/api", "main"))]);
+    /// match domgr.apps().validate(&spec).retrieve() {
+    ///     Ok(proposal) => println!("Proposal: {}", proposal),
+    ///     Err(e)       => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn apps(&self) -> RequestBuilder<'t, response::Apps> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["apps"]))
+    }
+
+    /// Returns a request that can be used to view, update or delete an existing App Platform
+    /// app, or to manage its deployments and logs
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::request::LogType;
+    /// # use doapi::DoRequest;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// let deployment = domgr.app("1234").deployments().create(true).retrieve_and_wait().unwrap();
+    /// match domgr.app("1234").deployment(&deployment.id).logs(LogType::Build).retrieve() {
+    ///     Ok(logs) => println!("Logs: {}", logs),
+    ///     Err(e)   => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn app(&self, id: &str) -> RequestBuilder<'t, response::App> {
+        RequestBuilder::new(self.auth, self.transport.clone(), self.endpoint(&["apps", id]))
+    }
+
+    /// Returns a request that can be used to list or create uptime checks
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::UptimeCheck;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// let check = UptimeCheck::new("api", "https", "https://api.example.com", &["us_east"]);
+    /// match domgr.uptime_checks().create(&check).retrieve() {
+    ///     Ok(check) => println!("Check: {}", check),
+    ///     Err(e)    => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn uptime_checks(&self) -> RequestBuilder<'t, response::UptimeChecks> {
+        RequestBuilder::new(self.auth,
+                            self.transport.clone(),
+                            self.endpoint(&["uptime", "checks"]))
+    }
+
+    /// Returns a request that can be used to view, update or delete an existing uptime check,
+    /// to see its state per region, or to manage its alerts
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # use doapi::request::UptimeAlert;
+    /// let domgr = DoManager::with_token("asfasdfasdf");
+    /// let mut alert = UptimeAlert::threshold("slow", "latency", "greater_than", 500, "5m");
+    /// alert.notifications.email.push("ops@example.com".to_owned());
+    /// domgr.uptime_check("1234").alerts().create(&alert).retrieve().unwrap();
+    /// match domgr.uptime_check("1234").state().retrieve() {
+    ///     Ok(state) => println!("Up everywhere: {}", state.is_up()),
+    ///     Err(e)    => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn uptime_check(&self, id: &str) -> RequestBuilder<'t, response::UptimeCheck> {
         RequestBuilder::new(self.auth,
-                            format!("https://api.digitalocean.com/v2/domains/{}", name))
+                            self.transport.clone(),
+                            self.endpoint(&["uptime", "checks", id]))
     }
 }
@@ -0,0 +1,198 @@
+use std::error::Error as StdError;
+use std::fmt;
+use std::io;
+
+use hyper;
+use serde::de::Deserialize;
+use serde_json::{self, Value};
+use url;
+
+use response::{Action, Deployment, DoError};
+
+/// The error type returned by every request made through `doapi`
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be sent, or the response could not be read
+    Transport(hyper::Error),
+    /// DigitalOcean answered with a non-success HTTP status code. `error` holds the parsed error
+    /// body (`id`, `message` and `request_id`)
+    Api {
+        status: u16,
+        error: DoError,
+    },
+    /// The response body could not be deserialized into the requested type. `path` is a JSON
+    /// pointer (i.e. `/droplets/3` for the fourth item of a list) to the value which failed, or
+    /// empty if the body isn't valid JSON
+    Deserialize {
+        path: String,
+        cause: serde_json::Error,
+    },
+    /// The rate limit for the account has been used up (HTTP 429). `reset` is the time (in UNIX
+    /// epoch seconds) at which the quota will be restored
+    RateLimited {
+        limit: f64,
+        reset: f64,
+    },
+    /// A required response header was either missing or malformed
+    Header(String),
+    /// A URL (such as the base URL, or a pagination link) could not be parsed
+    Url(url::ParseError),
+    /// An action being waited on finished with the status `errored`
+    ActionErrored(Action),
+    /// An action being waited on was still in progress when the `Wait` timeout expired
+    ActionTimeout(Action),
+    /// An app deployment being waited on ended in the `ERROR` or `CANCELED` phase
+    DeploymentFailed(Deployment),
+    /// An app deployment being waited on was still in progress when the `Wait` timeout expired
+    DeploymentTimeout(Deployment),
+    /// The input for a request was rejected before sending it (i.e. a malformed PEM certificate)
+    Invalid(String),
+}
+
+impl Error {
+    /// Builds the appropriate error from a non-success response
+    #[doc(hidden)]
+    pub fn from_status(status: u16, body: &str, limit: Option<f64>, reset: Option<f64>) -> Error {
+        if status == 429 {
+            return Error::RateLimited {
+                limit: limit.unwrap_or(0.0),
+                reset: reset.unwrap_or(0.0),
+            };
+        }
+        let error = match serde_json::from_str::<DoError>(body) {
+            Ok(e) => e,
+            Err(..) => {
+                DoError {
+                    id: status.to_string(),
+                    message: body.to_owned(),
+                    request_id: None,
+                }
+            }
+        };
+        Error::Api {
+            status: status,
+            error: error,
+        }
+    }
+
+    /// Returns the HTTP status code DigitalOcean responded with, if there was one
+    pub fn status(&self) -> Option<u16> {
+        match *self {
+            Error::Api { status, .. } => Some(status),
+            Error::RateLimited { .. } => Some(429),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Transport(ref e) => write!(f, "Transport error: {}", e),
+            Error::Api { status, ref error } => write!(f, "HTTP {}: {}", status, error),
+            Error::Deserialize { ref path, ref cause } => {
+                write!(f,
+                       "Failed to deserialize '{}': {}",
+                       if path.is_empty() {
+                           "/"
+                       } else {
+                           &path[..]
+                       },
+                       cause)
+            }
+            Error::RateLimited { limit, reset } => {
+                write!(f,
+                       "Rate limit of {:.0} requests exhausted, resets at {:.0}",
+                       limit,
+                       reset)
+            }
+            Error::Header(ref s) => write!(f, "Header error: {}", s),
+            Error::Url(ref e) => write!(f, "Invalid URL: {}", e),
+            Error::ActionErrored(ref a) => write!(f, "Action {:.0} errored", a.id),
+            Error::ActionTimeout(ref a) => {
+                write!(f, "Timed out waiting for action {:.0} ({})", a.id, a.status)
+            }
+            Error::DeploymentFailed(ref d) => write!(f, "Deployment {} {}", d.id, d.phase),
+            Error::DeploymentTimeout(ref d) => {
+                write!(f, "Timed out waiting for deployment {} ({})", d.id, d.phase)
+            }
+            Error::Invalid(ref s) => write!(f, "Invalid input: {}", s),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::Transport(ref e) => e.description(),
+            Error::Api { .. } => "DigitalOcean returned an error response",
+            Error::Deserialize { .. } => "failed to deserialize response",
+            Error::RateLimited { .. } => "rate limit exhausted",
+            Error::Header(ref s) => &s[..],
+            Error::Url(ref e) => e.description(),
+            Error::ActionErrored(..) => "action errored",
+            Error::ActionTimeout(..) => "timed out waiting for action",
+            Error::DeploymentFailed(..) => "deployment failed",
+            Error::DeploymentTimeout(..) => "timed out waiting for deployment",
+            Error::Invalid(ref s) => &s[..],
+        }
+    }
+
+    fn cause(&self) -> Option<&StdError> {
+        match *self {
+            Error::Transport(ref e) => Some(e),
+            Error::Deserialize { ref cause, .. } => Some(cause),
+            Error::Url(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<hyper::Error> for Error {
+    fn from(e: hyper::Error) -> Self { Error::Transport(e) }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self { Error::Transport(hyper::Error::Io(e)) }
+}
+
+impl From<url::ParseError> for Error {
+    fn from(e: url::ParseError) -> Self { Error::Url(e) }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Deserialize {
+            path: String::new(),
+            cause: e,
+        }
+    }
+}
+
+/// Deserializes `v` into a `T`, recording `path` as the location of `v` should it fail
+pub fn decode<T>(v: Value, path: &str) -> Result<T, Error>
+    where T: Deserialize
+{
+    serde_json::from_value(v).map_err(|e| {
+        Error::Deserialize {
+            path: path.to_owned(),
+            cause: e,
+        }
+    })
+}
+
+/// Deserializes the array `v` one element at a time, recording `path` followed by the index of
+/// the first element which fails (i.e. `/droplets/3`)
+pub fn decode_seq<T>(v: Value, path: &str) -> Result<Vec<T>, Error>
+    where T: Deserialize
+{
+    match v {
+        Value::Array(items) => {
+            items.into_iter()
+                 .enumerate()
+                 .map(|(i, item)| decode(item, &format!("{}/{}", path, i)[..]))
+                 .collect()
+        }
+        v => decode(v, path),
+    }
+}
@@ -183,7 +183,3 @@ macro_rules! doapi_enum {
         }
     };
 }
-
-macro_rules! regex(
-    ($s:expr) => (::regex::Regex::new($s).unwrap());
-);
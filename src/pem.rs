@@ -0,0 +1,102 @@
+//! Minimal validation of PEM encoded keys and certificates (RFC 7468), so that malformed input
+//! is rejected before it is sent to DigitalOcean.
+
+const BEGIN: &'static str = "-----BEGIN ";
+const END: &'static str = "-----END ";
+const DASHES: &'static str = "-----";
+
+/// Returns the label (i.e. `CERTIFICATE`) of every block in `input`, in order. Text outside of
+/// the blocks is ignored, but `input` must contain at least one block.
+pub fn labels(input: &str) -> Result<Vec<String>, String> {
+    let mut labels = vec![];
+    let mut current: Option<(String, String)> = None;
+    for line in input.lines().map(|l| l.trim()) {
+        let (label, mut body) = match current.take() {
+            Some(block) => block,
+            None => {
+                if line.starts_with(BEGIN) {
+                    current = Some((try!(label_of(line, BEGIN)), String::new()));
+                }
+                continue;
+            }
+        };
+        if line.starts_with(END) {
+            let end = try!(label_of(line, END));
+            if end != label {
+                return Err(format!("'{}' block ends as '{}'", label, end));
+            }
+            try!(check_base64(&body[..]).map_err(|e| format!("'{}' block {}", label, e)));
+            labels.push(label);
+        } else if line.contains(':') {
+            return Err(format!("'{}' block has headers, encrypted keys are not supported",
+                               label));
+        } else {
+            body.push_str(line);
+            current = Some((label, body));
+        }
+    }
+    if let Some((label, _)) = current {
+        return Err(format!("'{}' block is missing its END line", label));
+    }
+    if labels.is_empty() {
+        return Err("no PEM block found".to_owned());
+    }
+    Ok(labels)
+}
+
+fn label_of(line: &str, prefix: &str) -> Result<String, String> {
+    if !line.ends_with(DASHES) || line.len() < prefix.len() + DASHES.len() {
+        return Err(format!("malformed line '{}'", line));
+    }
+    Ok(line[prefix.len()..line.len() - DASHES.len()].to_owned())
+}
+
+fn check_base64(body: &str) -> Result<(), String> {
+    if body.is_empty() {
+        return Err("is empty".to_owned());
+    }
+    let data = body.trim_right_matches('=');
+    if body.len() % 4 != 0 || body.len() - data.len() > 2 {
+        return Err("is not valid base64 (bad length or padding)".to_owned());
+    }
+    let invalid = data.chars().find(|&c| {
+        match c {
+            'A'...'Z' | 'a'...'z' | '0'...'9' | '+' | '/' => false,
+            _ => true,
+        }
+    });
+    match invalid {
+        Some(c) => Err(format!("is not valid base64 (unexpected '{}')", c)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::labels;
+
+    const CERT: &'static str = "-----BEGIN CERTIFICATE-----\nMIIBszCCAVmgAwIBAgIU\nAA==\n\
+                                -----END CERTIFICATE-----\n";
+
+    #[test]
+    fn single() {
+        assert_eq!(labels(CERT).unwrap(), vec!["CERTIFICATE".to_owned()]);
+    }
+
+    #[test]
+    fn chain() {
+        let chain = format!("subject=/CN=example.com\n{}\n{}", CERT, CERT);
+        assert_eq!(labels(&chain[..]).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(labels("").is_err());
+        assert!(labels("MIIBszCCAVmgAwIBAgIU").is_err());
+        assert!(labels(&CERT.replace("-----END CERTIFICATE-----", "")[..]).is_err());
+        assert!(labels(&CERT.replace("END CERTIFICATE", "END PRIVATE KEY")[..]).is_err());
+        assert!(labels(&CERT.replace("AA==", "AA=")[..]).is_err());
+        assert!(labels(&CERT.replace("AA==", "A*==")[..]).is_err());
+        assert!(labels(&CERT.replace("MIIB", "Proc-Type: 4,ENCRYPTED\nMIIB")[..]).is_err());
+    }
+}
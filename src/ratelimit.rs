@@ -0,0 +1,243 @@
+//! Tracking of DigitalOcean's per-account rate limit, along with throttling and retrying of
+//! requests.
+//!
+//! Every response from DigitalOcean carries the `ratelimit-limit`, `ratelimit-remaining` and
+//! `ratelimit-reset` headers. A `DoManager` records these after each request (see
+//! `DoManager::rate_limit`), and before sending a request sleeps until the quota is reset if the
+//! remaining requests have dropped to the `Policy` floor. Requests made with idempotent methods
+//! (`GET`, `HEAD`, `PUT`, `DELETE`, ...) which fail with HTTP 429 or a `5xx` status are retried
+//! with an exponential backoff.
+
+use std::cmp;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hyper::method::Method;
+
+use transport::{HttpResponse, Transport};
+use Error;
+
+/// The state of the account's rate limit as of the latest response
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateLimit {
+    /// The number of requests allowed per hour
+    pub limit: f64,
+    /// The number of requests left until the reset
+    pub remaining: f64,
+    /// The time (in UNIX epoch seconds) at which `remaining` is restored to `limit`
+    pub reset: f64,
+}
+
+impl RateLimit {
+    /// Reads the rate limit headers of `resp`, returning `None` if any of them is missing
+    pub fn from_response(resp: &HttpResponse) -> Option<RateLimit> {
+        let get = |name: &str| resp.header(name).and_then(|v| v.trim().parse::<f64>().ok());
+        match (get("ratelimit-limit"), get("ratelimit-remaining"), get("ratelimit-reset")) {
+            (Some(limit), Some(remaining), Some(reset)) => {
+                Some(RateLimit {
+                    limit: limit,
+                    remaining: remaining,
+                    reset: reset,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the time left until the quota is reset
+    pub fn until_reset(&self) -> Duration {
+        let now = SystemTime::now()
+                      .duration_since(UNIX_EPOCH)
+                      .map(|d| d.as_secs())
+                      .unwrap_or(0);
+        if self.reset > now as f64 {
+            Duration::from_secs(self.reset as u64 - now)
+        } else {
+            Duration::from_secs(0)
+        }
+    }
+}
+
+/// Configures how a `DoManager` throttles and retries requests, see
+/// `DoManagerBuilder::policy`.
+///
+/// By default requests are held back for up to 60 seconds once the remaining quota hits `0`, and
+/// idempotent requests are retried up to 3 times, waiting 1 second before the first retry and
+/// doubling the wait for each retry after that (up to 60 seconds). A retry after HTTP 429 waits
+/// for the quota to reset, again for no longer than 60 seconds.
+///
+/// To opt out, use `max_wait(Duration::from_secs(0))` so requests are never held back, and
+/// `max_retries(0)` so failed requests are returned straight away.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use doapi::DoManager;
+/// # use doapi::ratelimit::Policy;
+/// // Leave 100 requests for other clients of the account, and never retry
+/// let domgr = DoManager::builder()
+///                 .token("asfasdfasdf")
+///                 .policy(Policy::new().floor(100).max_retries(0))
+///                 .build()
+///                 .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Policy {
+    floor: f64,
+    max_retries: u32,
+    backoff: Duration,
+    max_backoff: Duration,
+    max_wait: Duration,
+}
+
+impl Policy {
+    /// Creates a new `Policy` with the default settings
+    pub fn new() -> Policy {
+        Policy {
+            floor: 0.0,
+            max_retries: 3,
+            backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_wait: Duration::from_secs(60),
+        }
+    }
+
+    /// Sets the number of remaining requests at which further requests are held back until the
+    /// quota is reset
+    pub fn floor(mut self, floor: u32) -> Policy {
+        self.floor = floor as f64;
+        self
+    }
+
+    /// Sets how many times a request failing with HTTP 429 or a `5xx` status is retried. Only
+    /// requests with idempotent methods are ever retried.
+    pub fn max_retries(mut self, retries: u32) -> Policy {
+        self.max_retries = retries;
+        self
+    }
+
+    /// Sets the time to wait before the first retry, which doubles with each following retry
+    pub fn backoff(mut self, backoff: Duration) -> Policy {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Sets the longest time to wait between two retries
+    pub fn max_backoff(mut self, max: Duration) -> Policy {
+        self.max_backoff = max;
+        self
+    }
+
+    /// Sets the longest time to hold a request back waiting for the quota to reset (defaults to
+    /// 60 seconds, the quota resets hourly). A zero duration never holds requests back.
+    pub fn max_wait(mut self, max: Duration) -> Policy {
+        self.max_wait = max;
+        self
+    }
+
+    fn wait_for_reset(&self, rl: &RateLimit) -> Duration {
+        cmp::min(rl.until_reset(), self.max_wait)
+    }
+}
+
+impl Default for Policy {
+    fn default() -> Policy { Policy::new() }
+}
+
+/// A `Transport` which applies a `Policy` to every request sent through the `Transport` it
+/// wraps, recording the rate limit of each response into `state`. `DoManager` wraps its
+/// transport in a `Throttle` automatically.
+pub struct Throttle {
+    inner: Arc<Transport>,
+    policy: Policy,
+    state: Arc<Mutex<Option<RateLimit>>>,
+}
+
+impl Throttle {
+    pub fn new(inner: Arc<Transport>,
+               policy: Policy,
+               state: Arc<Mutex<Option<RateLimit>>>)
+               -> Throttle {
+        Throttle {
+            inner: inner,
+            policy: policy,
+            state: state,
+        }
+    }
+
+    fn current(&self) -> Option<RateLimit> { *self.state.lock().unwrap() }
+
+    fn record(&self, resp: &HttpResponse) {
+        if let Some(rl) = RateLimit::from_response(resp) {
+            *self.state.lock().unwrap() = Some(rl);
+        }
+    }
+}
+
+impl Transport for Throttle {
+    fn send(&self,
+            method: Method,
+            url: &str,
+            headers: &[(String, String)],
+            body: Option<&[u8]>)
+            -> Result<HttpResponse, Error> {
+        let mut backoff = self.policy.backoff;
+        let mut retries = 0;
+        loop {
+            // Retries have already waited out their backoff
+            if retries == 0 {
+                if let Some(rl) = self.current() {
+                    if rl.remaining <= self.policy.floor {
+                        let wait = self.policy.wait_for_reset(&rl);
+                        debug!("{} requests remaining, waiting {:?}", rl.remaining, wait);
+                        thread::sleep(wait);
+                    }
+                }
+            }
+            let resp = try!(self.inner.send(method.clone(), url, headers, body));
+            self.record(&resp);
+            let retryable = resp.status == 429 || resp.status >= 500;
+            if !retryable || !is_idempotent(&method) || retries >= self.policy.max_retries {
+                return Ok(resp);
+            }
+            retries += 1;
+            let wait = match (resp.status, RateLimit::from_response(&resp)) {
+                (429, Some(ref rl)) => cmp::max(self.policy.wait_for_reset(rl), backoff),
+                _ => backoff,
+            };
+            debug!("HTTP {} from {}, retry {} in {:?}", resp.status, url, retries, wait);
+            thread::sleep(wait);
+            backoff = cmp::min(backoff * 2, self.policy.max_backoff);
+        }
+    }
+}
+
+fn is_idempotent(method: &Method) -> bool {
+    match *method {
+        Method::Get | Method::Head | Method::Put | Method::Delete | Method::Options |
+        Method::Trace => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use super::{Policy, RateLimit};
+
+    #[test]
+    fn default_wait_is_bounded() {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let rl = RateLimit {
+            limit: 5000.0,
+            remaining: 0.0,
+            reset: (now + 3600) as f64,
+        };
+        assert_eq!(Policy::default().wait_for_reset(&rl), Duration::from_secs(60));
+        let off = Policy::new().max_wait(Duration::from_secs(0));
+        assert_eq!(off.wait_for_reset(&rl), Duration::from_secs(0));
+    }
+}
@@ -22,10 +22,10 @@ impl<'t> RequestBuilder<'t, response::Account> {
     ///     Err(e)     => println!("Error: {}", e)
     /// }
     /// ```
-    pub fn action(self, id: &str) -> RequestBuilder<'t, response::Action> {
+    pub fn action(mut self, id: &str) -> RequestBuilder<'t, response::Action> {
         // https://api.digitalocean.com/v2/actions/$ID
-        RequestBuilder::new(self.auth,
-                            format!("https://api.digitalocean.com/v2/actions/{}", id))
+        self.replace_path(&["actions", id]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
     }
 
     /// A type of `RequestBuilder` that lets you make requests for multiple actions or the concept
@@ -43,9 +43,94 @@ impl<'t> RequestBuilder<'t, response::Account> {
     ///     Err(e)     => println!("Error: {}", e)
     /// }
     /// ```
-    pub fn actions(self) -> RequestBuilder<'t, response::Actions> {
+    pub fn actions(mut self) -> RequestBuilder<'t, response::Actions> {
         // https://api.digitalocean.com/v2/actions
-        RequestBuilder::new(self.auth, "https://api.digitalocean.com/v2/actions")
+        self.replace_path(&["actions"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+
+    /// Returns a type of `RequestBuilder` for the account's current balance and month to date
+    /// usage
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.account().balance().retrieve() {
+    ///     Ok(balance) => println!("Usage so far: ${}", balance.month_to_date_usage),
+    ///     Err(e)      => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn balance(mut self) -> RequestBuilder<'t, response::Balance> {
+        // https://api.digitalocean.com/v2/customers/my/balance
+        self.replace_path(&["customers", "my", "balance"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+
+    /// Returns a type of `RequestBuilder` for the account's billing history (invoices, payments,
+    /// credits, ...), which is paginated
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::request::PagedRequest;
+    /// # let domgr = DoManager::with_token("asfasdfasdf");
+    /// for entry in domgr.account().billing_history().iter() {
+    ///     println!("{}", entry.unwrap());
+    /// }
+    /// ```
+    pub fn billing_history(mut self) -> RequestBuilder<'t, response::BillingHistory> {
+        // https://api.digitalocean.com/v2/customers/my/billing_history
+        self.replace_path(&["customers", "my", "billing_history"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+
+    /// Returns a type of `RequestBuilder` for the account's invoices, which are paginated
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.account().invoices().retrieve() {
+    ///     Ok(invoices) => println!("Invoices: {:?}", invoices),
+    ///     Err(e)       => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn invoices(mut self) -> RequestBuilder<'t, response::Invoices> {
+        // https://api.digitalocean.com/v2/customers/my/invoices
+        self.replace_path(&["customers", "my", "invoices"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+
+    /// Returns a type of `RequestBuilder` for the line items of a single invoice, which can also
+    /// be used to download the invoice or to retrieve its summary
+    ///
+    /// **Parameters:**
+    /// `uuid`: The UUID of the invoice (see `Invoice::invoice_uuid`)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use std::io::Write;
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # let domgr = DoManager::with_token("asfasdfasdf");
+    /// let pdf = domgr.account().invoice("22737513-0ea7-4206-8ceb-98a575af7681").pdf();
+    /// match pdf.retrieve() {
+    ///     Ok(pdf) => File::create("invoice.pdf").unwrap().write_all(&pdf.bytes).unwrap(),
+    ///     Err(e)  => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn invoice(mut self, uuid: &str) -> RequestBuilder<'t, response::InvoiceItems> {
+        // https://api.digitalocean.com/v2/customers/my/invoices/$UUID
+        self.replace_path(&["customers", "my", "invoices", uuid]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
     }
 }
 
@@ -1,5 +1,40 @@
+use hyper::method::Method;
+
 use response;
-use request::RequestBuilder;
+use request::{RequestBuilder, Wait};
 use request::DoRequest;
+use Error;
+
+impl<'t> RequestBuilder<'t, response::Action> {
+    /// Performs the request, then blocks until the resulting action has completed or errored,
+    /// polling with the default `Wait` settings.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.droplet("1234").power_off().retrieve_and_wait() {
+    ///     Ok(action) => println!("Powered off: {}", action),
+    ///     Err(e)     => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn retrieve_and_wait(&self) -> Result<response::Action, Error> {
+        self.retrieve_and_wait_with(Wait::new())
+    }
+
+    /// Like `retrieve_and_wait`, but polls according to `wait`
+    pub fn retrieve_and_wait_with(&self, wait: Wait) -> Result<response::Action, Error> {
+        let action = try!(self.retrieve());
+        let mut rb: RequestBuilder<response::Action> =
+            RequestBuilder::new(self.auth, self.transport.clone(), self.url.clone());
+        // Actions are created by POSTing to ".../actions", and can then be found at
+        // ".../actions/$ACTION_ID"
+        if self.method == Method::Post {
+            rb.push_path(&[&format!("{:.0}", action.id)[..]]);
+        }
+        wait.run(action, || rb.retrieve())
+    }
+}
 
 impl<'t> DoRequest<response::Action> for RequestBuilder<'t, response::Action> {}
@@ -0,0 +1,256 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+use serde_json;
+
+use response::{self, AppSpec};
+use request::{RequestBuilder, Wait};
+use request::DoRequest;
+use Error;
+
+/// The kinds of logs an app deployment writes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogType {
+    Build,
+    Deploy,
+    Run,
+}
+
+impl LogType {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            LogType::Build => "BUILD",
+            LogType::Deploy => "DEPLOY",
+            LogType::Run => "RUN",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Spec<'a> {
+    spec: &'a AppSpec,
+}
+
+#[derive(Serialize)]
+struct Proposal<'a> {
+    spec: &'a AppSpec,
+    #[serde(skip_serializing_if="Option::is_none")]
+    app_id: Option<String>,
+}
+
+#[derive(Serialize)]
+struct NewDeployment {
+    force_build: bool,
+}
+
+impl<'t> RequestBuilder<'t, response::Apps> {
+    pub fn create(self, spec: &AppSpec) -> RequestBuilder<'t, response::App> {
+        // POST: "https://api.digitalocean.com/v2/apps"
+        // body:
+        //      "spec" : { "name" : "web", "services" : [...], ... }
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&Spec { spec: spec }).unwrap()),
+        }
+    }
+    pub fn validate(mut self, spec: &AppSpec) -> RequestBuilder<'t, response::AppProposal> {
+        // POST: "https://api.digitalocean.com/v2/apps/propose"
+        // body:
+        //      "spec" : { "name" : "web", "services" : [...], ... }
+        self.push_path(&["propose"]);
+        let body = Proposal {
+            spec: spec,
+            app_id: None,
+        };
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&body).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::App> {
+    pub fn update(self, spec: &AppSpec) -> RequestBuilder<'t, response::App> {
+        // PUT: "https://api.digitalocean.com/v2/apps/$ID"
+        // body:
+        //      "spec" : { "name" : "web", "services" : [...], ... }
+        RequestBuilder {
+            method: Method::Put,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&Spec { spec: spec }).unwrap()),
+        }
+    }
+    pub fn validate(mut self, spec: &AppSpec) -> RequestBuilder<'t, response::AppProposal> {
+        // POST: "https://api.digitalocean.com/v2/apps/propose"
+        // body:
+        //      "spec" : { "name" : "web", "services" : [...], ... }
+        //      "app_id" : "$ID"
+        let id = self.url.path_segments().and_then(|s| s.last()).unwrap_or("").to_owned();
+        self.replace_path(&["propose"]);
+        let body = Proposal {
+            spec: spec,
+            app_id: Some(id),
+        };
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&body).unwrap()),
+        }
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/apps/$ID"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+    pub fn deployments(mut self) -> RequestBuilder<'t, response::Deployments> {
+        // GET: "https://api.digitalocean.com/v2/apps/$ID/deployments"
+        self.push_path(&["deployments"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn deployment(mut self, id: &str) -> RequestBuilder<'t, response::Deployment> {
+        // GET: "https://api.digitalocean.com/v2/apps/$ID/deployments/$DEPLOYMENT_ID"
+        self.push_path(&["deployments", id]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn logs(mut self,
+                component: &str,
+                log_type: LogType)
+                -> RequestBuilder<'t, response::AppLogs> {
+        // GET: "https://api.digitalocean.com/v2/apps/$ID/components/$COMPONENT/logs?type=RUN"
+        // (the logs of the active deployment)
+        self.push_path(&["components", component, "logs"]);
+        self.set_query("type", log_type.as_str());
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::Deployments> {
+    pub fn create(self, force_build: bool) -> RequestBuilder<'t, response::Deployment> {
+        // POST: "https://api.digitalocean.com/v2/apps/$ID/deployments"
+        // body:
+        //      "force_build" : true
+        let body = NewDeployment { force_build: force_build };
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&body).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::Deployment> {
+    pub fn cancel(mut self) -> RequestBuilder<'t, response::Deployment> {
+        // POST: "https://api.digitalocean.com/v2/apps/$ID/deployments/$DEPLOYMENT_ID/cancel"
+        self.push_path(&["cancel"]);
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+    pub fn logs(mut self, log_type: LogType) -> RequestBuilder<'t, response::AppLogs> {
+        // GET: "https://api.digitalocean.com/v2/apps/$ID/deployments/$DEPLOYMENT_ID/logs?
+        //       type=BUILD"
+        self.push_path(&["logs"]);
+        self.set_query("type", log_type.as_str());
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn component_logs(mut self,
+                          component: &str,
+                          log_type: LogType)
+                          -> RequestBuilder<'t, response::AppLogs> {
+        // GET: "https://api.digitalocean.com/v2/apps/$ID/deployments/$DEPLOYMENT_ID/components/
+        //       $COMPONENT/logs?type=BUILD"
+        self.push_path(&["components", component, "logs"]);
+        self.set_query("type", log_type.as_str());
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+
+    /// Performs the request, then blocks until the deployment has gone live or failed, polling
+    /// with the default `Wait` settings. A failed deployment is returned as
+    /// `Error::DeploymentFailed`, except when the request was a `cancel`, in which case waiting
+    /// succeeds once the deployment has been canceled.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # let domgr = DoManager::with_token("asfasdfasdf");
+    /// match domgr.app("1234").deployments().create(false).retrieve_and_wait() {
+    ///     Ok(deployment) => println!("Deployed: {}", deployment),
+    ///     Err(e)         => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn retrieve_and_wait(&self) -> Result<response::Deployment, Error> {
+        self.retrieve_and_wait_with(Wait::deployment())
+    }
+
+    /// Like `retrieve_and_wait`, but polls according to `wait`
+    pub fn retrieve_and_wait_with(&self,
+                                  wait: Wait<response::Deployment>)
+                                  -> Result<response::Deployment, Error> {
+        let deployment = try!(self.retrieve());
+        let mut rb: RequestBuilder<response::Deployment> =
+            RequestBuilder::new(self.auth, self.transport.clone(), self.url.clone());
+        // Deployments are created by POSTing to ".../deployments", and canceled by POSTing to
+        // ".../deployments/$DEPLOYMENT_ID/cancel"
+        let canceling = self.method == Method::Post && self.url.path().ends_with("/cancel");
+        if canceling {
+            rb.replace_path(&[]);
+        } else if self.method == Method::Post {
+            rb.push_path(&[&deployment.id[..]]);
+        }
+        match wait.run_deployment(deployment, || rb.retrieve()) {
+            Err(Error::DeploymentFailed(deployment)) => {
+                if canceling && deployment.is_canceled() {
+                    Ok(deployment)
+                } else {
+                    Err(Error::DeploymentFailed(deployment))
+                }
+            }
+            res => res,
+        }
+    }
+}
+
+impl<'t> DoRequest<response::App> for RequestBuilder<'t, response::App> {}
+
+impl<'t> DoRequest<response::Deployment> for RequestBuilder<'t, response::Deployment> {}
+
+impl<'t> DoRequest<response::AppProposal> for RequestBuilder<'t, response::AppProposal> {
+    fn retrieve(&self) -> Result<response::AppProposal, Error> {
+        self.retrieve_unwrapped()
+    }
+}
+
+impl<'t> DoRequest<response::AppLogs> for RequestBuilder<'t, response::AppLogs> {
+    fn retrieve(&self) -> Result<response::AppLogs, Error> {
+        self.retrieve_unwrapped()
+    }
+}
@@ -0,0 +1,45 @@
+use response;
+use request::RequestBuilder;
+use request::DoRequest;
+use Error;
+
+impl<'t> RequestBuilder<'t, response::InvoiceItems> {
+    pub fn csv(mut self) -> RequestBuilder<'t, response::InvoiceFile> {
+        // GET: "https://api.digitalocean.com/v2/customers/my/invoices/$UUID/csv"
+        self.push_path(&["csv"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn pdf(mut self) -> RequestBuilder<'t, response::InvoiceFile> {
+        // GET: "https://api.digitalocean.com/v2/customers/my/invoices/$UUID/pdf"
+        self.push_path(&["pdf"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn summary(mut self) -> RequestBuilder<'t, response::InvoiceSummary> {
+        // GET: "https://api.digitalocean.com/v2/customers/my/invoices/$UUID/summary"
+        self.push_path(&["summary"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+}
+
+impl<'t> DoRequest<response::Balance> for RequestBuilder<'t, response::Balance> {
+    fn retrieve(&self) -> Result<response::Balance, Error> {
+        self.retrieve_unwrapped()
+    }
+}
+
+impl<'t> DoRequest<response::InvoiceSummary> for RequestBuilder<'t, response::InvoiceSummary> {
+    fn retrieve(&self) -> Result<response::InvoiceSummary, Error> {
+        self.retrieve_unwrapped()
+    }
+}
+
+impl<'t> DoRequest<response::InvoiceFile> for RequestBuilder<'t, response::InvoiceFile> {
+    fn retrieve(&self) -> Result<response::InvoiceFile, Error> {
+        // The invoice is a CSV or PDF file, which is handed back untouched
+        let resp = try!(self.retrieve_success());
+        Ok(response::InvoiceFile {
+            content_type: resp.header("content-type").map(|s| s.to_owned()),
+            bytes: resp.body,
+        })
+    }
+}
@@ -0,0 +1,155 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+use serde_json;
+
+use response;
+use request::RequestBuilder;
+use request::DoRequest;
+
+// origin (true)    String      The fully qualified domain name (FQDN) for the origin server
+// which provides the content for the CDN. This is currently restricted to a Space (i.e.
+// "static-images.nyc3.digitaloceanspaces.com").
+// ttl              Number      The amount of time the content is cached by the CDN's edge
+// servers in seconds (60, 600, 3600, 86400 or 604800). Defaults to 3600 (one hour).
+// certificate_id   String      The ID of a DigitalOcean managed TLS certificate used for SSL
+// when a custom subdomain is provided.
+// custom_domain    String      The fully qualified domain name (FQDN) of the custom subdomain
+// to be associated with the CDN endpoint. When used, a `certificate_id` must be provided.
+#[derive(Serialize, Debug, Clone)]
+pub struct CdnEndpoint {
+    pub origin: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub ttl: Option<u32>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub certificate_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub custom_domain: Option<String>,
+}
+
+impl CdnEndpoint {
+    /// An endpoint for `origin` with the default TTL and no custom domain
+    pub fn new(origin: &str) -> CdnEndpoint {
+        CdnEndpoint {
+            origin: origin.to_owned(),
+            ttl: None,
+            certificate_id: None,
+            custom_domain: None,
+        }
+    }
+}
+
+// ttl              Number      The amount of time the content is cached by the CDN's edge
+// servers in seconds.
+// certificate_id   String      The ID of the certificate used for the custom subdomain. An
+// empty string removes it.
+// custom_domain    String      The custom subdomain of the endpoint. An empty string removes
+// it.
+/// The changes to make to a CDN endpoint, see `update()`. Anything not set is left unchanged,
+/// and the origin of an endpoint can't be changed.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct CdnEndpointUpdate {
+    #[serde(skip_serializing_if="Option::is_none")]
+    ttl: Option<u32>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    certificate_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    custom_domain: Option<String>,
+}
+
+impl CdnEndpointUpdate {
+    /// An update which leaves everything unchanged
+    pub fn new() -> CdnEndpointUpdate { CdnEndpointUpdate::default() }
+
+    /// Sets the time content is cached for, in seconds
+    pub fn ttl(mut self, ttl: u32) -> CdnEndpointUpdate {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Serves the endpoint from `domain`, using the certificate `certificate_id`
+    pub fn custom_domain(mut self, domain: &str, certificate_id: &str) -> CdnEndpointUpdate {
+        self.custom_domain = Some(domain.to_owned());
+        self.certificate_id = Some(certificate_id.to_owned());
+        self
+    }
+
+    /// Replaces the certificate of the custom domain, leaving the domain itself unchanged
+    pub fn certificate(mut self, certificate_id: &str) -> CdnEndpointUpdate {
+        self.certificate_id = Some(certificate_id.to_owned());
+        self
+    }
+
+    /// Removes the custom domain along with its certificate
+    pub fn remove_custom_domain(mut self) -> CdnEndpointUpdate {
+        self.custom_domain = Some(String::new());
+        self.certificate_id = Some(String::new());
+        self
+    }
+}
+
+#[derive(Serialize)]
+struct Files {
+    files: Vec<String>,
+}
+
+impl<'t> RequestBuilder<'t, response::CdnEndpoints> {
+    pub fn create(self, endpoint: &CdnEndpoint) -> RequestBuilder<'t, response::CdnEndpoint> {
+        // POST: "https://api.digitalocean.com/v2/cdn/endpoints"
+        // body: see request::CdnEndpoint
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(endpoint).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::CdnEndpoint> {
+    pub fn update(self, update: &CdnEndpointUpdate) -> RequestBuilder<'t, response::CdnEndpoint> {
+        // PUT: "https://api.digitalocean.com/v2/cdn/endpoints/$ID"
+        // body:
+        //      "ttl" : 1800
+        //      "certificate_id" : "892071a0-bb95-49bc-8021-3afd67a210bf"
+        //      "custom_domain" : "static.example.com"
+        RequestBuilder {
+            method: Method::Put,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(update).unwrap()),
+        }
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/cdn/endpoints/$ID"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+    pub fn purge(mut self, files: &[&str]) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/cdn/endpoints/$ID/cache"
+        // body:
+        //      "files" : ["assets/img/hero.png", "assets/css/*"]
+        self.push_path(&["cache"]);
+        let body = Files { files: files.iter().map(|f| (*f).to_owned()).collect() };
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&body).unwrap()),
+        }
+    }
+}
+
+impl<'t> DoRequest<response::CdnEndpoint> for RequestBuilder<'t, response::CdnEndpoint> {}
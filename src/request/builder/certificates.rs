@@ -0,0 +1,123 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+use serde_json;
+
+use response;
+use request::RequestBuilder;
+use request::DoRequest;
+use pem;
+use Error;
+
+// name (true)      String      A unique human-readable name referring to a certificate.
+// type             String      The type of certificate, "custom" (the default) or
+// "lets_encrypt".
+// private_key      String      The contents of a PEM-formatted private-key corresponding to the
+// SSL certificate. Required for "custom" certificates.
+// leaf_certificate String      The contents of a PEM-formatted public SSL certificate.
+// Required for "custom" certificates.
+// certificate_chain String     The full PEM-formatted trust chain between the certificate
+// authority's certificate and your domain's SSL certificate.
+// dns_names        Array       An array of fully qualified domain names (FQDNs) for which the
+// certificate will be issued. The domains must be managed using DigitalOcean's DNS. Required for
+// "lets_encrypt" certificates.
+#[derive(Serialize, Debug, Clone)]
+pub struct Certificate {
+    name: String,
+    #[serde(rename="type")]
+    cert_type: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    private_key: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    leaf_certificate: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    certificate_chain: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    dns_names: Option<Vec<String>>,
+}
+
+impl Certificate {
+    /// A custom certificate from PEM encoded input, which is validated before any request is
+    /// made. `private_key` must hold a single unencrypted private key, `leaf` a single
+    /// certificate and `chain` (if given) one or more certificates.
+    pub fn custom(name: &str,
+                  private_key: &str,
+                  leaf: &str,
+                  chain: Option<&str>)
+                  -> Result<Certificate, Error> {
+        let key = try!(pem_labels("private_key", private_key));
+        if key.len() != 1 || !key[0].ends_with("PRIVATE KEY") ||
+           key[0] == "ENCRYPTED PRIVATE KEY" {
+            return Err(Error::Invalid("private_key must be a single unencrypted private key"
+                                          .to_owned()));
+        }
+        let cert = try!(pem_labels("leaf_certificate", leaf));
+        if cert.len() != 1 || cert[0] != "CERTIFICATE" {
+            return Err(Error::Invalid("leaf_certificate must be a single certificate"
+                                          .to_owned()));
+        }
+        if let Some(chain) = chain {
+            let certs = try!(pem_labels("certificate_chain", chain));
+            if certs.iter().any(|l| l != "CERTIFICATE") {
+                return Err(Error::Invalid("certificate_chain must only contain certificates"
+                                              .to_owned()));
+            }
+        }
+        Ok(Certificate {
+            name: name.to_owned(),
+            cert_type: "custom".to_owned(),
+            private_key: Some(private_key.to_owned()),
+            leaf_certificate: Some(leaf.to_owned()),
+            certificate_chain: chain.map(|c| c.to_owned()),
+            dns_names: None,
+        })
+    }
+
+    /// A certificate issued by Let's Encrypt for `dns_names`, whose domains must be managed
+    /// through DigitalOcean (see `DoManager::domains`)
+    pub fn lets_encrypt(name: &str, dns_names: &[&str]) -> Certificate {
+        Certificate {
+            name: name.to_owned(),
+            cert_type: "lets_encrypt".to_owned(),
+            private_key: None,
+            leaf_certificate: None,
+            certificate_chain: None,
+            dns_names: Some(dns_names.iter().map(|n| (*n).to_owned()).collect()),
+        }
+    }
+}
+
+fn pem_labels(field: &str, input: &str) -> Result<Vec<String>, Error> {
+    pem::labels(input).map_err(|e| Error::Invalid(format!("{}: {}", field, e)))
+}
+
+impl<'t> RequestBuilder<'t, response::Certificates> {
+    pub fn create(self, cert: &Certificate) -> RequestBuilder<'t, response::Certificate> {
+        // POST: "https://api.digitalocean.com/v2/certificates"
+        // body: see request::Certificate
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(cert).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::Certificate> {
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/certificates/$ID"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+}
+
+impl<'t> DoRequest<response::Certificate> for RequestBuilder<'t, response::Certificate> {}
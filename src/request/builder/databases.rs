@@ -0,0 +1,406 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+use serde_json;
+
+use response::{self, DatabaseSize, Engine, RegionSlug, TrustedSource};
+use request::RequestBuilder;
+use request::DoRequest;
+
+// name (true)          String      A unique, human-readable name referring to a database
+// cluster.
+// engine (true)        String      A slug representing the database engine used for the
+// cluster ("pg", "mysql", "redis" or "mongodb").
+// size (true)          String      The slug identifier representing the size of the nodes in the
+// cluster (i.e. "db-s-1vcpu-1gb").
+// region (true)        String      The slug identifier for the region where the cluster will be
+// created.
+// num_nodes (true)     Number      The number of nodes in the cluster, including the primary.
+// version              String      The version of the database engine to use. Defaults to the
+// latest version.
+// tags                 Array       The tags to apply to the cluster.
+// private_network_uuid String      The ID of the VPC to place the cluster into, defaults to the
+// region's default VPC.
+#[derive(Serialize, Debug, Clone)]
+pub struct Database {
+    pub name: String,
+    pub engine: Engine,
+    pub size: DatabaseSize,
+    pub region: RegionSlug,
+    pub num_nodes: u32,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub private_network_uuid: Option<String>,
+}
+
+impl Database {
+    /// A cluster running the latest version of `engine`. The size and region may be given as
+    /// slugs, or the region as a `response::Region`
+    pub fn new<S, R>(name: &str, engine: Engine, size: S, region: R, num_nodes: u32) -> Database
+        where S: Into<DatabaseSize>,
+              R: Into<RegionSlug>
+    {
+        Database {
+            name: name.to_owned(),
+            engine: engine,
+            size: size.into(),
+            region: region.into(),
+            num_nodes: num_nodes,
+            version: None,
+            tags: None,
+            private_network_uuid: None,
+        }
+    }
+}
+
+// name (true)      String      A unique name for the connection pool.
+// mode (true)      String      The PGBouncer transaction mode for the connection pool
+// ("session", "transaction" or "statement").
+// size (true)      Number      The desired size of the PGBouncer connection pool.
+// db (true)        String      The database for use with the connection pool.
+// user (true)      String      The name of the user for use with the connection pool.
+#[derive(Serialize, Debug, Clone)]
+pub struct ConnectionPool {
+    pub name: String,
+    pub mode: String,
+    pub size: u32,
+    pub db: String,
+    pub user: String,
+}
+
+impl ConnectionPool {
+    pub fn new(name: &str, mode: &str, size: u32, db: &str, user: &str) -> ConnectionPool {
+        ConnectionPool {
+            name: name.to_owned(),
+            mode: mode.to_owned(),
+            size: size,
+            db: db.to_owned(),
+            user: user.to_owned(),
+        }
+    }
+}
+
+// name (true)      String      The name to give the read-only replica.
+// region           String      The slug identifier for the region the replica will be located
+// in. Defaults to the region of the primary.
+// size             String      The slug identifier of the size of the replica. Defaults to the
+// size of the primary.
+#[derive(Serialize, Debug, Clone)]
+pub struct DatabaseReplica {
+    pub name: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub region: Option<RegionSlug>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub size: Option<DatabaseSize>,
+}
+
+impl DatabaseReplica {
+    /// A replica in the same region and of the same size as the primary
+    pub fn new(name: &str) -> DatabaseReplica {
+        DatabaseReplica {
+            name: name.to_owned(),
+            region: None,
+            size: None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Resize {
+    size: DatabaseSize,
+    num_nodes: u32,
+}
+
+#[derive(Serialize)]
+struct Migrate {
+    region: RegionSlug,
+}
+
+#[derive(Serialize)]
+struct Maintenance<'a> {
+    day: &'a str,
+    hour: &'a str,
+}
+
+#[derive(Serialize)]
+struct Rules {
+    rules: Vec<TrustedSource>,
+}
+
+#[derive(Serialize)]
+struct Name<'a> {
+    name: &'a str,
+}
+
+impl<'t> RequestBuilder<'t, response::Databases> {
+    pub fn with_tag(mut self, tag: &str) -> RequestBuilder<'t, response::Databases> {
+        // GET: "https://api.digitalocean.com/v2/databases?tag_name=$TAG_NAME"
+        self.set_query("tag_name", tag);
+        self
+    }
+    pub fn create(self, db: &Database) -> RequestBuilder<'t, response::Database> {
+        // POST: "https://api.digitalocean.com/v2/databases"
+        // body: see request::Database
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(db).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::Database> {
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/databases/$ID"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+    pub fn resize<S>(self, size: S, num_nodes: u32) -> RequestBuilder<'t, response::HeaderOnly>
+        where S: Into<DatabaseSize>
+    {
+        // PUT: "https://api.digitalocean.com/v2/databases/$ID/resize"
+        // body:
+        //      "size" : "db-s-4vcpu-8gb"
+        //      "num_nodes" : 3
+        let body = Resize {
+            size: size.into(),
+            num_nodes: num_nodes,
+        };
+        self.put("resize", serde_json::to_string(&body).unwrap())
+    }
+    pub fn migrate<R>(self, region: R) -> RequestBuilder<'t, response::HeaderOnly>
+        where R: Into<RegionSlug>
+    {
+        // PUT: "https://api.digitalocean.com/v2/databases/$ID/migrate"
+        // body:
+        //      "region" : "lon1"
+        let body = Migrate { region: region.into() };
+        self.put("migrate", serde_json::to_string(&body).unwrap())
+    }
+    pub fn maintenance_window(self,
+                              day: &str,
+                              hour: &str)
+                              -> RequestBuilder<'t, response::HeaderOnly> {
+        // PUT: "https://api.digitalocean.com/v2/databases/$ID/maintenance"
+        // body:
+        //      "day" : "tuesday"
+        //      "hour" : "14:00"
+        let body = Maintenance {
+            day: day,
+            hour: hour,
+        };
+        self.put("maintenance", serde_json::to_string(&body).unwrap())
+    }
+    pub fn firewall(mut self) -> RequestBuilder<'t, response::TrustedSources> {
+        // GET: "https://api.digitalocean.com/v2/databases/$ID/firewall"
+        self.push_path(&["firewall"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn set_firewall(self, rules: &[TrustedSource]) -> RequestBuilder<'t, response::HeaderOnly> {
+        // PUT: "https://api.digitalocean.com/v2/databases/$ID/firewall"
+        // body:
+        //      "rules" : [{ "type" : "ip_addr", "value" : "192.168.1.1" }]
+        // All existing rules are replaced
+        let body = Rules { rules: rules.to_vec() };
+        self.put("firewall", serde_json::to_string(&body).unwrap())
+    }
+    pub fn users(mut self) -> RequestBuilder<'t, response::DatabaseUsers> {
+        // GET: "https://api.digitalocean.com/v2/databases/$ID/users"
+        self.push_path(&["users"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn user(mut self, name: &str) -> RequestBuilder<'t, response::DatabaseUser> {
+        // GET: "https://api.digitalocean.com/v2/databases/$ID/users/$USERNAME"
+        self.push_path(&["users", name]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn dbs(mut self) -> RequestBuilder<'t, response::DatabaseDbs> {
+        // GET: "https://api.digitalocean.com/v2/databases/$ID/dbs"
+        self.push_path(&["dbs"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn db(mut self, name: &str) -> RequestBuilder<'t, response::DatabaseDb> {
+        // GET: "https://api.digitalocean.com/v2/databases/$ID/dbs/$DB_NAME"
+        self.push_path(&["dbs", name]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn pools(mut self) -> RequestBuilder<'t, response::ConnectionPools> {
+        // GET: "https://api.digitalocean.com/v2/databases/$ID/pools"
+        self.push_path(&["pools"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn pool(mut self, name: &str) -> RequestBuilder<'t, response::ConnectionPool> {
+        // GET: "https://api.digitalocean.com/v2/databases/$ID/pools/$POOL_NAME"
+        self.push_path(&["pools", name]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn replicas(mut self) -> RequestBuilder<'t, response::DatabaseReplicas> {
+        // GET: "https://api.digitalocean.com/v2/databases/$ID/replicas"
+        self.push_path(&["replicas"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn replica(mut self, name: &str) -> RequestBuilder<'t, response::DatabaseReplica> {
+        // GET: "https://api.digitalocean.com/v2/databases/$ID/replicas/$REPLICA_NAME"
+        self.push_path(&["replicas", name]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn backups(mut self) -> RequestBuilder<'t, response::DatabaseBackups> {
+        // GET: "https://api.digitalocean.com/v2/databases/$ID/backups"
+        self.push_path(&["backups"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+
+    fn put(mut self, path: &str, body: String) -> RequestBuilder<'t, response::HeaderOnly> {
+        self.push_path(&[path]);
+        RequestBuilder {
+            method: Method::Put,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(body),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::DatabaseUsers> {
+    pub fn create(self, name: &str) -> RequestBuilder<'t, response::DatabaseUser> {
+        // POST: "https://api.digitalocean.com/v2/databases/$ID/users"
+        // body:
+        //      "name" : "app-01"
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&Name { name: name }).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::DatabaseDbs> {
+    pub fn create(self, name: &str) -> RequestBuilder<'t, response::DatabaseDb> {
+        // POST: "https://api.digitalocean.com/v2/databases/$ID/dbs"
+        // body:
+        //      "name" : "alpha"
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&Name { name: name }).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::ConnectionPools> {
+    pub fn create(self, pool: &ConnectionPool) -> RequestBuilder<'t, response::ConnectionPool> {
+        // POST: "https://api.digitalocean.com/v2/databases/$ID/pools"
+        // body: see request::ConnectionPool
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(pool).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::DatabaseReplicas> {
+    pub fn create(self,
+                  replica: &DatabaseReplica)
+                  -> RequestBuilder<'t, response::DatabaseReplica> {
+        // POST: "https://api.digitalocean.com/v2/databases/$ID/replicas"
+        // body: see request::DatabaseReplica
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(replica).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::DatabaseUser> {
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/databases/$ID/users/$USERNAME"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::DatabaseDb> {
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/databases/$ID/dbs/$DB_NAME"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::ConnectionPool> {
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/databases/$ID/pools/$POOL_NAME"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::DatabaseReplica> {
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/databases/$ID/replicas/$REPLICA_NAME"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+}
+
+impl<'t> DoRequest<response::Database> for RequestBuilder<'t, response::Database> {}
+
+impl<'t> DoRequest<response::DatabaseUser> for RequestBuilder<'t, response::DatabaseUser> {}
+
+impl<'t> DoRequest<response::DatabaseDb> for RequestBuilder<'t, response::DatabaseDb> {}
+
+impl<'t> DoRequest<response::ConnectionPool> for RequestBuilder<'t, response::ConnectionPool> {}
+
+impl<'t> DoRequest<response::DatabaseReplica> for RequestBuilder<'t, response::DatabaseReplica> {}
@@ -142,6 +142,7 @@ impl<'t> RequestBuilder<'t, response::DnsRecords> {
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(serde_json::to_string(record).ok().unwrap()),
@@ -228,6 +229,7 @@ impl<'t> RequestBuilder<'t, response::DnsRecord> {
         RequestBuilder {
             method: Method::Put,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(s),
@@ -256,6 +258,7 @@ impl<'t> RequestBuilder<'t, response::DnsRecord> {
         RequestBuilder {
             method: Method::Delete,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: None,
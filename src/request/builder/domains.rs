@@ -31,6 +31,7 @@ impl<'t> RequestBuilder<'t, response::Domains> {
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"name\":{:?},\"ip_address\":{:?}}}", name, ip)),
@@ -60,6 +61,7 @@ impl<'t> RequestBuilder<'t, response::Domain> {
         RequestBuilder {
             method: Method::Delete,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: None,
@@ -85,9 +87,8 @@ impl<'t> RequestBuilder<'t, response::Domain> {
     /// ```
     pub fn dns_records(mut self) -> RequestBuilder<'t, response::DnsRecords> {
         // GET: "https://api.digitalocean.com/v2/domains/$DOMAIN/records"
-        self.url.push('/');
-        self.url.push_str("records");
-        RequestBuilder::new(self.auth, self.url)
+        self.push_path(&["records"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
     }
 
     /// Returns type of `RequestBuilder` which allows you make requests related to a single DNS
@@ -108,9 +109,8 @@ impl<'t> RequestBuilder<'t, response::Domain> {
     /// ```
     pub fn dns_record(mut self, id: &str) -> RequestBuilder<'t, response::DnsRecord> {
         // GET "https://api.digitalocean.com/v2/domains/$DOMAIN/records/$ID"
-        self.url.push('/');
-        self.url.push_str(id);
-        RequestBuilder::new(self.auth, self.url)
+        self.push_path(&["records", id]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
     }
 }
 
@@ -7,6 +7,7 @@ use serde_json;
 use response;
 use request::RequestBuilder;
 use request::DoRequest;
+use Error;
 
 // name (true)  String           The human-readable string you wish to use when
 // displaying the
@@ -41,6 +42,9 @@ use request::DoRequest;
 // user_data     String          A string of the desired User Data for the
 // Droplet. User Data is currently only available in regions with metadata
 // listed in their features.
+// vpc_uuid      String          The unique identifier of the VPC to which the
+// Droplet will be assigned. If excluded, the Droplet will be assigned to the
+// region's default VPC.
 #[derive(Serialize)]
 pub struct Droplet {
     pub name: String,
@@ -52,6 +56,8 @@ pub struct Droplet {
     pub ipv6: bool,
     pub private_networking: bool,
     pub user_data: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub vpc_uuid: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -65,6 +71,8 @@ struct DropletWithId {
     pub ipv6: bool,
     pub private_networking: bool,
     pub user_data: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub vpc_uuid: Option<String>,
 }
 
 impl DropletWithId {
@@ -79,6 +87,7 @@ impl DropletWithId {
             ipv6: d.ipv6,
             private_networking: d.private_networking,
             user_data: d.user_data.clone(),
+            vpc_uuid: d.vpc_uuid.clone(),
         }
     }
 }
@@ -94,7 +103,8 @@ impl fmt::Display for Droplet {
              Backups Enabled: {}\n\
              IPv6 Enabled: {}\n\
              Private Networking Enabled: {}\n\
-             User Data: {}\n",
+             User Data: {}\n\
+             VPC: {}\n",
                self.name,
                self.region,
                self.size,
@@ -111,7 +121,8 @@ impl fmt::Display for Droplet {
                    d
                } else {
                    "None".to_owned()
-               })
+               },
+               self.vpc_uuid.as_ref().map(|s| &s[..]).unwrap_or("None"))
     }
 }
 
@@ -126,7 +137,8 @@ impl fmt::Display for DropletWithId {
              Backups Enabled: {}\n\
              IPv6 Enabled: {}\n\
              Private Networking Enabled: {}\n\
-             User Data: {}\n",
+             User Data: {}\n\
+             VPC: {}\n",
                self.name,
                self.region,
                self.size,
@@ -143,7 +155,8 @@ impl fmt::Display for DropletWithId {
                    d
                } else {
                    "None".to_owned()
-               })
+               },
+               self.vpc_uuid.as_ref().map(|s| &s[..]).unwrap_or("None"))
     }
 }
 
@@ -152,10 +165,11 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // POST: "https://api.digitalocean.com/v2/droplets/$ID/actions"
         // body:
         //      "type" : "disable_backups"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"disable_backups\"}}")),
@@ -165,10 +179,11 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // POST: "https://api.digitalocean.com/v2/droplets/$ID/actions"
         // body:
         //      "type" : "reboot"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"reboot\"}}")),
@@ -178,10 +193,11 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // POST: "https://api.digitalocean.com/v2/droplets/$ID/actions"
         // body:
         //      "type" : "power_cycle"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"power_cycle\"}}")),
@@ -191,10 +207,11 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // POST: "https://api.digitalocean.com/v2/droplets/$ID/actions"
         // body:
         //      "type" : "shutdown"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"shutdown\"}}")),
@@ -204,10 +221,11 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // POST: "https://api.digitalocean.com/v2/droplets/$ID/actions"
         // body:
         //      "type" : "power_off"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"power_off\"}}")),
@@ -217,10 +235,11 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // POST: "https://api.digitalocean.com/v2/droplets/$ID/actions"
         // body:
         //      "type" : "power_on"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"power_on\"}}")),
@@ -231,7 +250,7 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // body:
         //      "type" : "restore"
         //      "image": 12356          // number if ID, string if slug
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         let image = match img.parse::<u64>() {
             Ok(_) => img.to_string(),
             Err(_) => format!("\"{}\"", img),
@@ -239,6 +258,7 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"restore\",\"image\":{}}}", image)),
@@ -248,10 +268,11 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // POST: "https://api.digitalocean.com/v2/droplets/$ID/actions"
         // body:
         //      "type" : "password_reset"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"password_reset\"}}")),
@@ -263,10 +284,11 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         //      "type" : "resize"
         //      "disk" : bool
         //      "size" : "1gb"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"resize\",\"disk\":{},\"size\":{:?}}}",
@@ -279,7 +301,7 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // body:
         //      "type" : "rebuild"
         //      "image": 12356          // number if ID, string if slug
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         let image = match img.parse::<u64>() {
             Ok(_) => img.to_string(),
             Err(_) => format!("\"{}\"", img),
@@ -287,6 +309,7 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"rebuild\",\"image\":{}}}", image)),
@@ -297,10 +320,11 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // body:
         //      "type" : "rename"
         //      "name" : "some name"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"rename\",\"name\":{:?}}}", name)),
@@ -311,10 +335,11 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // body:
         //      "type" : "change_kernel"
         //      "kernel" :  1234566
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"change_kernel\",\"kernel\":{}}}", kernel)),
@@ -324,10 +349,11 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // POST: "https://api.digitalocean.com/v2/droplets/$ID/actions"
         // body:
         //      "type" : "enable_ipv6"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"enable_ipv6\"}}")),
@@ -337,10 +363,11 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // POST: "https://api.digitalocean.com/v2/droplets/$ID/actions"
         // body:
         //      "type" : "enable_private_networking"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"enable_private_networking\"}}")),
@@ -351,10 +378,11 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // body:
         //      "type" : "snapshot"
         //      "name" : "some name"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"snapshot\",\"name\":{:?}}}", name)),
@@ -364,10 +392,11 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
         // POST: "https://api.digitalocean.com/v2/droplets/$ID/actions"
         // body:
         //      "type" : "upgrade"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"upgrade\"}}")),
@@ -375,35 +404,35 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
     }
     pub fn action(mut self, id: &str) -> RequestBuilder<'t, response::Action> {
         // GET: "https://api.digitalocean.com/v2/droplets/$ID/actions/$ACTION_ID"
-        self.url.push_str("/actions/");
-        self.url.push_str(id);
-        RequestBuilder::new(self.auth, self.url)
+        self.push_path(&["actions", id]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
     }
     pub fn kernels(mut self) -> RequestBuilder<'t, response::Kernels> {
         // GET: "https://api.digitalocean.com/v2/droplets/$ID/kernels"
-        self.url.push_str("/kernels");
-        RequestBuilder::new(self.auth, self.url)
+        self.push_path(&["kernels"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
     }
     pub fn snapshots(mut self) -> RequestBuilder<'t, response::Snapshots> {
         // GET: "https://api.digitalocean.com/v2/droplets/$ID/snapshots"
-        self.url.push_str("/snapshots");
-        RequestBuilder::new(self.auth, self.url)
+        self.push_path(&["snapshots"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
     }
     pub fn backups(mut self) -> RequestBuilder<'t, response::Backups> {
         // GET: "https://api.digitalocean.com/v2/droplets/$ID/backups"
-        self.url.push_str("/backups");
-        RequestBuilder::new(self.auth, self.url)
+        self.push_path(&["backups"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
     }
     pub fn actions(mut self) -> RequestBuilder<'t, response::Actions> {
         // GET: "https://api.digitalocean.com/v2/droplets/$ID/actions"
-        self.url.push_str("/actions");
-        RequestBuilder::new(self.auth, self.url)
+        self.push_path(&["actions"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
     }
     pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
         // DELETE: "https://api.digitalocean.com/v2/droplets/$ID"
         RequestBuilder {
             method: Method::Delete,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: None,
@@ -412,8 +441,8 @@ impl<'t> RequestBuilder<'t, response::Droplet> {
     pub fn show(&self) -> RequestBuilder<'t, response::Droplet> { unimplemented!() }
     pub fn neighbors(mut self) -> RequestBuilder<'t, response::Droplets> {
         // GET: "https://api.digitalocean.com/v2/droplets/$ID/neighbors"
-        self.url.push_str("/neighbors");
-        RequestBuilder::new(self.auth, self.url)
+        self.push_path(&["neighbors"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
     }
 }
 
@@ -430,6 +459,7 @@ impl<'t> RequestBuilder<'t, response::Droplets> {
         //      "ipv6" : ""
         //      "private_networking" : ""
         //      "user_data" : ""
+        //      "vpc_uuid" : ""
 
         // FIXME: don't unwrap()
 
@@ -438,6 +468,7 @@ impl<'t> RequestBuilder<'t, response::Droplets> {
             return RequestBuilder {
                 method: Method::Post,
                 auth: self.auth,
+                transport: self.transport,
                 url: self.url,
                 resp_t: PhantomData,
                 body: Some(serde_json::to_string(&d).ok().unwrap()),
@@ -447,22 +478,93 @@ impl<'t> RequestBuilder<'t, response::Droplets> {
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(serde_json::to_string(droplet).ok().unwrap()),
         }
 
     }
-    pub fn neighbors(self) -> RequestBuilder<'t, response::Neighbors> {
+    pub fn neighbors(mut self) -> RequestBuilder<'t, response::Neighbors> {
         // GET: "https://api.digitalocean.com/v2/reports/droplet_neighbors"
-        RequestBuilder::new(self.auth,
-                            "https://api.digitalocean.com/v2/reports/droplet_neighbors")
+        self.replace_path(&["reports", "droplet_neighbors"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
     }
-    pub fn upgrades(self) -> RequestBuilder<'t, response::ResponseStringArray> {
+    pub fn upgrades(mut self) -> RequestBuilder<'t, response::ResponseStringArray> {
         // GET: "https://api.digitalocean.com/v2/droplet_upgrades"
-        RequestBuilder::new(self.auth,
-                            "https://api.digitalocean.com/v2/droplet_upgrades")
+        self.replace_path(&["droplet_upgrades"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn with_tag(mut self, tag: &str) -> RequestBuilder<'t, response::TaggedDroplets> {
+        // GET: "https://api.digitalocean.com/v2/droplets?tag_name=$TAG_NAME"
+        self.set_query("tag_name", tag);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::TaggedDroplets> {
+    pub fn droplets(self) -> RequestBuilder<'t, response::Droplets> {
+        // GET: "https://api.digitalocean.com/v2/droplets?tag_name=$TAG_NAME"
+        // (for paging through the tagged droplets)
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn power_off(self) -> RequestBuilder<'t, response::Actions> {
+        // POST: "https://api.digitalocean.com/v2/droplets/actions?tag_name=$TAG_NAME"
+        // body:
+        //      "type" : "power_off"
+        self.bulk_action("{\"type\":\"power_off\"}".to_owned())
+    }
+    pub fn power_on(self) -> RequestBuilder<'t, response::Actions> {
+        // POST: "https://api.digitalocean.com/v2/droplets/actions?tag_name=$TAG_NAME"
+        // body:
+        //      "type" : "power_on"
+        self.bulk_action("{\"type\":\"power_on\"}".to_owned())
+    }
+    pub fn shutdown(self) -> RequestBuilder<'t, response::Actions> {
+        // POST: "https://api.digitalocean.com/v2/droplets/actions?tag_name=$TAG_NAME"
+        // body:
+        //      "type" : "shutdown"
+        self.bulk_action("{\"type\":\"shutdown\"}".to_owned())
+    }
+    pub fn snapshot(self, name: &str) -> RequestBuilder<'t, response::Actions> {
+        // POST: "https://api.digitalocean.com/v2/droplets/actions?tag_name=$TAG_NAME"
+        // body:
+        //      "type" : "snapshot"
+        //      "name" : "Nifty New Snapshot"
+        self.bulk_action(format!("{{\"type\":\"snapshot\",\"name\":{:?}}}", name))
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/droplets?tag_name=$TAG_NAME"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+
+    // The tag query set by `with_tag` is kept, so the action applies to every tagged droplet
+    fn bulk_action(mut self, body: String) -> RequestBuilder<'t, response::Actions> {
+        self.push_path(&["actions"]);
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(body),
+        }
     }
 }
 
 impl<'t> DoRequest<response::Droplet> for RequestBuilder<'t, response::Droplet> {}
+
+impl<'t> DoRequest<response::Droplets> for RequestBuilder<'t, response::TaggedDroplets> {
+    fn retrieve(&self) -> Result<response::Droplets, Error> {
+        let rb: RequestBuilder<response::Droplets> =
+            RequestBuilder::new(self.auth, self.transport.clone(), self.url.clone());
+        rb.retrieve()
+    }
+}
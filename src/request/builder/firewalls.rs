@@ -0,0 +1,172 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+use serde_json;
+
+use response::{self, InboundRule, OutboundRule};
+use request::RequestBuilder;
+use request::DoRequest;
+
+// name (true)      String      A human-readable name for a firewall.
+// inbound_rules    Array       The inbound access rule block for the firewall.
+// outbound_rules   Array       The outbound access rule block for the firewall.
+// droplet_ids      Array       An array containing the IDs of the Droplets assigned to the
+// firewall.
+// tags             Array       An array containing the names of the Tags assigned to the
+// firewall.
+#[derive(Serialize, Debug, Clone)]
+pub struct Firewall {
+    pub name: String,
+    pub inbound_rules: Vec<InboundRule>,
+    pub outbound_rules: Vec<OutboundRule>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub droplet_ids: Option<Vec<u64>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+impl Firewall {
+    /// A firewall without any rules, droplets or tags
+    pub fn new(name: &str) -> Firewall {
+        Firewall {
+            name: name.to_owned(),
+            inbound_rules: vec![],
+            outbound_rules: vec![],
+            droplet_ids: None,
+            tags: None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DropletIds {
+    droplet_ids: Vec<u64>,
+}
+
+#[derive(Serialize)]
+struct Tags {
+    tags: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct Rules {
+    #[serde(skip_serializing_if="Vec::is_empty")]
+    inbound_rules: Vec<InboundRule>,
+    #[serde(skip_serializing_if="Vec::is_empty")]
+    outbound_rules: Vec<OutboundRule>,
+}
+
+impl<'t> RequestBuilder<'t, response::Firewalls> {
+    pub fn create(self, firewall: &Firewall) -> RequestBuilder<'t, response::Firewall> {
+        // POST: "https://api.digitalocean.com/v2/firewalls"
+        // body: see request::Firewall
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(firewall).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::Firewall> {
+    pub fn update(self, firewall: &Firewall) -> RequestBuilder<'t, response::Firewall> {
+        // PUT: "https://api.digitalocean.com/v2/firewalls/$ID"
+        // body: see request::Firewall, all rules, droplets and tags are replaced
+        RequestBuilder {
+            method: Method::Put,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(firewall).unwrap()),
+        }
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/firewalls/$ID"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+    pub fn add_droplets(self, ids: &[u64]) -> RequestBuilder<'t, response::HeaderOnly> {
+        // POST: "https://api.digitalocean.com/v2/firewalls/$ID/droplets"
+        // body:
+        //      "droplet_ids" : [49696269]
+        let body = DropletIds { droplet_ids: ids.to_vec() };
+        self.sub_request(Method::Post, "droplets", serde_json::to_string(&body).unwrap())
+    }
+    pub fn remove_droplets(self, ids: &[u64]) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/firewalls/$ID/droplets"
+        // body:
+        //      "droplet_ids" : [49696269]
+        let body = DropletIds { droplet_ids: ids.to_vec() };
+        self.sub_request(Method::Delete, "droplets", serde_json::to_string(&body).unwrap())
+    }
+    pub fn add_tags(self, tags: &[&str]) -> RequestBuilder<'t, response::HeaderOnly> {
+        // POST: "https://api.digitalocean.com/v2/firewalls/$ID/tags"
+        // body:
+        //      "tags" : ["frontend"]
+        let body = Tags { tags: tags.iter().map(|t| (*t).to_owned()).collect() };
+        self.sub_request(Method::Post, "tags", serde_json::to_string(&body).unwrap())
+    }
+    pub fn remove_tags(self, tags: &[&str]) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/firewalls/$ID/tags"
+        // body:
+        //      "tags" : ["frontend"]
+        let body = Tags { tags: tags.iter().map(|t| (*t).to_owned()).collect() };
+        self.sub_request(Method::Delete, "tags", serde_json::to_string(&body).unwrap())
+    }
+    pub fn add_rules(self,
+                     inbound: &[InboundRule],
+                     outbound: &[OutboundRule])
+                     -> RequestBuilder<'t, response::HeaderOnly> {
+        // POST: "https://api.digitalocean.com/v2/firewalls/$ID/rules"
+        // body:
+        //      "inbound_rules" : [{ "protocol" : "tcp", "ports" : "3306", "sources" : {...} }]
+        //      "outbound_rules" : [{ "protocol" : "tcp", "ports" : "0", "destinations" : {...} }]
+        let body = Rules {
+            inbound_rules: inbound.to_vec(),
+            outbound_rules: outbound.to_vec(),
+        };
+        self.sub_request(Method::Post, "rules", serde_json::to_string(&body).unwrap())
+    }
+    pub fn remove_rules(self,
+                        inbound: &[InboundRule],
+                        outbound: &[OutboundRule])
+                        -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/firewalls/$ID/rules"
+        // body:
+        //      "inbound_rules" : [{ "protocol" : "tcp", "ports" : "3306", "sources" : {...} }]
+        //      "outbound_rules" : [{ "protocol" : "tcp", "ports" : "0", "destinations" : {...} }]
+        let body = Rules {
+            inbound_rules: inbound.to_vec(),
+            outbound_rules: outbound.to_vec(),
+        };
+        self.sub_request(Method::Delete, "rules", serde_json::to_string(&body).unwrap())
+    }
+
+    fn sub_request(mut self,
+                   method: Method,
+                   path: &str,
+                   body: String)
+                   -> RequestBuilder<'t, response::HeaderOnly> {
+        self.push_path(&[path]);
+        RequestBuilder {
+            method: method,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(body),
+        }
+    }
+}
+
+impl<'t> DoRequest<response::Firewall> for RequestBuilder<'t, response::Firewall> {}
@@ -0,0 +1,91 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+
+use response;
+use request::RequestBuilder;
+use request::DoRequest;
+
+impl<'t> RequestBuilder<'t, response::FloatingIps> {
+    pub fn create(self, region: &str) -> RequestBuilder<'t, response::FloatingIp> {
+        // POST: "https://api.digitalocean.com/v2/floating_ips"
+        // body:
+        //      "region" : "nyc3"
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(format!("{{\"region\":{:?}}}", region)),
+        }
+    }
+    pub fn create_assigned(self, droplet_id: u64) -> RequestBuilder<'t, response::FloatingIp> {
+        // POST: "https://api.digitalocean.com/v2/floating_ips"
+        // body:
+        //      "droplet_id" : 1234
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(format!("{{\"droplet_id\":{}}}", droplet_id)),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::FloatingIp> {
+    pub fn assign(mut self, droplet_id: u64) -> RequestBuilder<'t, response::Action> {
+        // POST: "https://api.digitalocean.com/v2/floating_ips/$IP/actions"
+        // body:
+        //      "type" : "assign"
+        //      "droplet_id" : 1234
+        self.push_path(&["actions"]);
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(format!("{{\"type\":\"assign\",\"droplet_id\":{}}}", droplet_id)),
+        }
+    }
+    pub fn unassign(mut self) -> RequestBuilder<'t, response::Action> {
+        // POST: "https://api.digitalocean.com/v2/floating_ips/$IP/actions"
+        // body:
+        //      "type" : "unassign"
+        self.push_path(&["actions"]);
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(r#"{"type":"unassign"}"#.to_owned()),
+        }
+    }
+    pub fn actions(mut self) -> RequestBuilder<'t, response::Actions> {
+        // GET: "https://api.digitalocean.com/v2/floating_ips/$IP/actions"
+        self.push_path(&["actions"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn action(mut self, id: &str) -> RequestBuilder<'t, response::Action> {
+        // GET: "https://api.digitalocean.com/v2/floating_ips/$IP/actions/$ACTION_ID"
+        self.push_path(&["actions", id]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/floating_ips/$IP"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+}
+
+impl<'t> DoRequest<response::FloatingIp> for RequestBuilder<'t, response::FloatingIp> {}
@@ -12,10 +12,11 @@ impl<'t> RequestBuilder<'t, response::Image> {
         // body:
         //      "type" : "transfer"
         //      "region" : "nyc2"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"type\":\"transfer\",\"region\":{:?}}}", region)),
@@ -25,10 +26,11 @@ impl<'t> RequestBuilder<'t, response::Image> {
         // POST: "https://api.digitalocean.com/v2/images/$ID/actions"
         // body:
         //      "type" : "convert"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(r#"{"type":"convert"}"#.to_owned()),
@@ -36,10 +38,11 @@ impl<'t> RequestBuilder<'t, response::Image> {
     }
     pub fn actions(mut self) -> RequestBuilder<'t, response::Actions> {
         // GET: "https://api.digitalocean.com/v2/images/$ID/actions"
-        self.url.push_str("/actions");
+        self.push_path(&["actions"]);
         RequestBuilder {
             url: self.url,
             auth: self.auth,
+            transport: self.transport,
             method: self.method,
             resp_t: PhantomData,
             body: None,
@@ -47,9 +50,8 @@ impl<'t> RequestBuilder<'t, response::Image> {
     }
     pub fn action(mut self, id: &str) -> RequestBuilder<'t, response::Action> {
         // GET: https://api.digitalocean.com/v2/images/$IMG_ID/actions/$ID
-        self.url.push_str("/actions/");
-        self.url.push_str(id);
-        RequestBuilder::new(self.auth, self.url)
+        self.push_path(&["actions", id]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
     }
 
     pub fn update(self, name: &str) -> RequestBuilder<'t, response::Image> {
@@ -60,6 +62,7 @@ impl<'t> RequestBuilder<'t, response::Image> {
             method: Method::Put,
             url: self.url,
             auth: self.auth,
+            transport: self.transport,
             resp_t: PhantomData,
             body: Some(format!("{{\"name\":{:?}}}", name)),
         }
@@ -69,6 +72,7 @@ impl<'t> RequestBuilder<'t, response::Image> {
         RequestBuilder {
             method: Method::Delete,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: None,
@@ -79,22 +83,22 @@ impl<'t> RequestBuilder<'t, response::Image> {
 impl<'t> RequestBuilder<'t, response::Images> {
     pub fn applications(mut self) -> RequestBuilder<'t, response::Images> {
         // GET: https://api.digitalocean.com/v2/images?type=applications
-        self.url.push_str("?type=applications");
+        self.set_query("type", "applications");
         self
     }
     pub fn distributions(mut self) -> RequestBuilder<'t, response::Images> {
         // GET: https://api.digitalocean.com/v2/images?type=distribtutions
-        self.url.push_str("?type=distributions");
+        self.set_query("type", "distributions");
         self
     }
     pub fn private(mut self) -> RequestBuilder<'t, response::Images> {
         // GET: https://api.digitalocean.com/v2/images?type=private
-        self.url.push_str("?type=private");
+        self.set_query("type", "private");
         self
     }
     pub fn available(mut self) -> RequestBuilder<'t, response::Images> {
         // GET: https://api.digitalocean.com/v2/images?type=available
-        self.url.push_str("?type=available");
+        self.set_query("type", "available");
         self
     }
 }
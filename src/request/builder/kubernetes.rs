@@ -0,0 +1,306 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+use serde_json;
+
+use response;
+use request::RequestBuilder;
+use request::DoRequest;
+use Error;
+
+// How long the token of a kubeconfig is valid for unless `expiry_seconds` is given (7 days),
+// used to estimate `Kubeconfig::estimated_token_expiry`
+const DEFAULT_EXPIRY: u64 = 604800;
+
+// name (true)          String      A human-readable name for a Kubernetes cluster.
+// region (true)        String      The slug identifier for the region where the cluster will be
+// created.
+// version (true)       String      The slug identifier for the version of Kubernetes used for
+// the cluster (see `kubernetes_clusters().options()`).
+// node_pools (true)    Array       The node pools to deploy in the cluster, at least one is
+// required.
+// vpc_uuid             String      The ID of the VPC where the cluster will be located.
+// tags                 Array       The tags to apply to the cluster.
+// auto_upgrade         Boolean     Whether the cluster will be automatically upgraded to new
+// patch releases during its maintenance window.
+#[derive(Serialize, Debug, Clone)]
+pub struct KubernetesCluster {
+    pub name: String,
+    pub region: String,
+    pub version: String,
+    pub node_pools: Vec<NodePool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub vpc_uuid: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub auto_upgrade: Option<bool>,
+}
+
+impl KubernetesCluster {
+    pub fn new(name: &str,
+               region: &str,
+               version: &str,
+               node_pools: Vec<NodePool>)
+               -> KubernetesCluster {
+        KubernetesCluster {
+            name: name.to_owned(),
+            region: region.to_owned(),
+            version: version.to_owned(),
+            node_pools: node_pools,
+            vpc_uuid: None,
+            tags: None,
+            auto_upgrade: None,
+        }
+    }
+}
+
+// name (true)      String      A human-readable name for the node pool.
+// size (true)      String      The slug identifier for the type of Droplet used as workers.
+// count (true)     Number      The number of Droplet instances in the node pool.
+// tags             Array       The tags to apply to the node pool.
+// auto_scale       Boolean     Whether auto-scaling is enabled for the node pool.
+// min_nodes        Number      The minimum number of nodes the pool can be scaled down to.
+// max_nodes        Number      The maximum number of nodes the pool can be scaled up to.
+#[derive(Serialize, Debug, Clone)]
+pub struct NodePool {
+    pub name: String,
+    pub size: String,
+    pub count: u32,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub auto_scale: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub min_nodes: Option<u32>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub max_nodes: Option<u32>,
+}
+
+impl NodePool {
+    /// A pool of `count` nodes of `size`, without auto-scaling
+    pub fn new(name: &str, size: &str, count: u32) -> NodePool {
+        NodePool {
+            name: name.to_owned(),
+            size: size.to_owned(),
+            count: count,
+            tags: None,
+            auto_scale: None,
+            min_nodes: None,
+            max_nodes: None,
+        }
+    }
+}
+
+// Only the name, tags and auto-upgrade setting of a cluster can be changed
+#[derive(Serialize)]
+struct ClusterUpdate<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if="Option::is_none")]
+    tags: Option<&'a Vec<String>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    auto_upgrade: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct Nodes {
+    nodes: Vec<String>,
+}
+
+impl<'t> RequestBuilder<'t, response::KubernetesClusters> {
+    pub fn create(self,
+                  cluster: &KubernetesCluster)
+                  -> RequestBuilder<'t, response::KubernetesCluster> {
+        // POST: "https://api.digitalocean.com/v2/kubernetes/clusters"
+        // body: see request::KubernetesCluster
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(cluster).unwrap()),
+        }
+    }
+    pub fn options(mut self) -> RequestBuilder<'t, response::KubernetesOptions> {
+        // GET: "https://api.digitalocean.com/v2/kubernetes/options"
+        self.replace_path(&["options"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::KubernetesCluster> {
+    pub fn update(self,
+                  cluster: &KubernetesCluster)
+                  -> RequestBuilder<'t, response::KubernetesCluster> {
+        // PUT: "https://api.digitalocean.com/v2/kubernetes/clusters/$ID"
+        // body:
+        //      "name" : "prod-cluster-01"          // true
+        //      "tags" : ["k8s"]
+        //      "auto_upgrade" : true
+        let body = ClusterUpdate {
+            name: &cluster.name[..],
+            tags: cluster.tags.as_ref(),
+            auto_upgrade: cluster.auto_upgrade,
+        };
+        RequestBuilder {
+            method: Method::Put,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&body).unwrap()),
+        }
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/kubernetes/clusters/$ID"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+    pub fn kubeconfig(mut self) -> RequestBuilder<'t, response::Kubeconfig> {
+        // GET: "https://api.digitalocean.com/v2/kubernetes/clusters/$ID/kubeconfig"
+        self.push_path(&["kubeconfig"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn upgrades(mut self) -> RequestBuilder<'t, response::KubernetesVersions> {
+        // GET: "https://api.digitalocean.com/v2/kubernetes/clusters/$ID/upgrades"
+        self.push_path(&["upgrades"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn upgrade(mut self, version: &str) -> RequestBuilder<'t, response::HeaderOnly> {
+        // POST: "https://api.digitalocean.com/v2/kubernetes/clusters/$ID/upgrade"
+        // body:
+        //      "version" : "1.18.8-do.0"
+        self.push_path(&["upgrade"]);
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(format!("{{\"version\":{:?}}}", version)),
+        }
+    }
+    pub fn node_pools(mut self) -> RequestBuilder<'t, response::NodePools> {
+        // GET: "https://api.digitalocean.com/v2/kubernetes/clusters/$ID/node_pools"
+        self.push_path(&["node_pools"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn node_pool(mut self, id: &str) -> RequestBuilder<'t, response::NodePool> {
+        // GET: "https://api.digitalocean.com/v2/kubernetes/clusters/$ID/node_pools/$POOL_ID"
+        self.push_path(&["node_pools", id]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::NodePools> {
+    pub fn create(self, pool: &NodePool) -> RequestBuilder<'t, response::NodePool> {
+        // POST: "https://api.digitalocean.com/v2/kubernetes/clusters/$ID/node_pools"
+        // body: see request::NodePool
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(pool).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::NodePool> {
+    pub fn update(self, pool: &NodePool) -> RequestBuilder<'t, response::NodePool> {
+        // PUT: "https://api.digitalocean.com/v2/kubernetes/clusters/$ID/node_pools/$POOL_ID"
+        // body: see request::NodePool, the size can't be changed
+        RequestBuilder {
+            method: Method::Put,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(pool).unwrap()),
+        }
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/kubernetes/clusters/$ID/node_pools/$POOL_ID"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+    pub fn recycle(mut self, node_ids: &[&str]) -> RequestBuilder<'t, response::HeaderOnly> {
+        // POST: "https://api.digitalocean.com/v2/kubernetes/clusters/$ID/node_pools/$POOL_ID/
+        //        recycle"
+        // body:
+        //      "nodes" : ["d8db5e1a-6103-43b5-a7b3-8a948210a9fc"]
+        self.push_path(&["recycle"]);
+        let body = Nodes { nodes: node_ids.iter().map(|n| (*n).to_owned()).collect() };
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&body).unwrap()),
+        }
+    }
+    pub fn delete_node(mut self,
+                       node_id: &str,
+                       replace: bool)
+                       -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/kubernetes/clusters/$ID/node_pools/$POOL_ID/
+        //          nodes/$NODE_ID?replace=1"
+        self.push_path(&["nodes", node_id]);
+        if replace {
+            self.set_query("replace", "1");
+        }
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::Kubeconfig> {
+    pub fn expiry_seconds(mut self, seconds: u64) -> RequestBuilder<'t, response::Kubeconfig> {
+        // GET: "https://api.digitalocean.com/v2/kubernetes/clusters/$ID/kubeconfig?
+        //       expiry_seconds=$SECONDS"
+        self.set_query("expiry_seconds", &seconds.to_string()[..]);
+        self
+    }
+}
+
+impl<'t> DoRequest<response::KubernetesCluster>
+    for RequestBuilder<'t, response::KubernetesCluster> {}
+
+impl<'t> DoRequest<response::NodePool> for RequestBuilder<'t, response::NodePool> {}
+
+impl<'t> DoRequest<response::KubernetesOptions>
+    for RequestBuilder<'t, response::KubernetesOptions> {}
+
+impl<'t> DoRequest<response::Kubeconfig> for RequestBuilder<'t, response::Kubeconfig> {
+    fn retrieve(&self) -> Result<response::Kubeconfig, Error> {
+        // The kubeconfig is YAML rather than JSON
+        let yaml = try!(self.retrieve_json());
+        let expiry = self.url
+                         .query_pairs()
+                         .find(|&(ref k, _)| k == "expiry_seconds")
+                         .and_then(|(_, v)| v.parse::<u64>().ok())
+                         .unwrap_or(DEFAULT_EXPIRY);
+        response::Kubeconfig::parse(yaml, expiry)
+    }
+}
@@ -0,0 +1,173 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+use serde_json;
+
+use response::{self, ForwardingRule, HealthCheck, StickySessions};
+use request::RequestBuilder;
+use request::DoRequest;
+
+// name (true)              String      A human-readable name for a Load Balancer instance.
+// region (true)            String      The unique slug identifier for the region where the Load
+// Balancer will be created.
+// forwarding_rules (true)  Array       An array of objects specifying the forwarding rules for a
+// Load Balancer. At least one forwarding rule is required.
+// algorithm                String      The load balancing algorithm ("round_robin" or
+// "least_connections"). Defaults to "round_robin".
+// health_check             Object      An object specifying health check settings for the Load
+// Balancer.
+// sticky_sessions          Object      An object specifying sticky sessions settings for the Load
+// Balancer.
+// redirect_http_to_https   Boolean     A boolean value indicating whether HTTP requests to the
+// Load Balancer on port 80 will be redirected to HTTPS on port 443.
+// droplet_ids              Array       An array containing the IDs of the Droplets assigned to
+// the Load Balancer.
+// tag                      String      The name of a Droplet tag corresponding to Droplets to be
+// assigned to the Load Balancer. Mutually exclusive with `droplet_ids`.
+#[derive(Serialize, Debug, Clone)]
+pub struct LoadBalancer {
+    pub name: String,
+    pub region: String,
+    pub forwarding_rules: Vec<ForwardingRule>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub algorithm: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub health_check: Option<HealthCheck>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub sticky_sessions: Option<StickySessions>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub redirect_http_to_https: Option<bool>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub droplet_ids: Option<Vec<u64>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tag: Option<String>,
+}
+
+impl LoadBalancer {
+    /// A load balancer with default settings for everything but the required fields
+    pub fn new(name: &str, region: &str, forwarding_rules: Vec<ForwardingRule>) -> LoadBalancer {
+        LoadBalancer {
+            name: name.to_owned(),
+            region: region.to_owned(),
+            forwarding_rules: forwarding_rules,
+            algorithm: None,
+            health_check: None,
+            sticky_sessions: None,
+            redirect_http_to_https: None,
+            droplet_ids: None,
+            tag: None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DropletIds {
+    droplet_ids: Vec<u64>,
+}
+
+#[derive(Serialize)]
+struct ForwardingRules {
+    forwarding_rules: Vec<ForwardingRule>,
+}
+
+impl<'t> RequestBuilder<'t, response::LoadBalancers> {
+    pub fn create(self, lb: &LoadBalancer) -> RequestBuilder<'t, response::LoadBalancer> {
+        // POST: "https://api.digitalocean.com/v2/load_balancers"
+        // body: see request::LoadBalancer
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(lb).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::LoadBalancer> {
+    pub fn update(self, lb: &LoadBalancer) -> RequestBuilder<'t, response::LoadBalancer> {
+        // PUT: "https://api.digitalocean.com/v2/load_balancers/$ID"
+        // body: see request::LoadBalancer, all settings are replaced
+        RequestBuilder {
+            method: Method::Put,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(lb).unwrap()),
+        }
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/load_balancers/$ID"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+    pub fn add_droplets(self, ids: &[u64]) -> RequestBuilder<'t, response::HeaderOnly> {
+        // POST: "https://api.digitalocean.com/v2/load_balancers/$ID/droplets"
+        // body:
+        //      "droplet_ids" : [3164444, 3164445]
+        self.droplets_req(Method::Post, ids)
+    }
+    pub fn remove_droplets(self, ids: &[u64]) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/load_balancers/$ID/droplets"
+        // body:
+        //      "droplet_ids" : [3164444, 3164445]
+        self.droplets_req(Method::Delete, ids)
+    }
+    pub fn add_forwarding_rules(self,
+                                rules: &[ForwardingRule])
+                                -> RequestBuilder<'t, response::HeaderOnly> {
+        // POST: "https://api.digitalocean.com/v2/load_balancers/$ID/forwarding_rules"
+        // body:
+        //      "forwarding_rules" : [{ "entry_protocol" : "tcp", "entry_port" : 3306, ... }]
+        self.forwarding_rules_req(Method::Post, rules)
+    }
+    pub fn remove_forwarding_rules(self,
+                                   rules: &[ForwardingRule])
+                                   -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/load_balancers/$ID/forwarding_rules"
+        // body:
+        //      "forwarding_rules" : [{ "entry_protocol" : "tcp", "entry_port" : 3306, ... }]
+        self.forwarding_rules_req(Method::Delete, rules)
+    }
+
+    fn droplets_req(mut self,
+                    method: Method,
+                    ids: &[u64])
+                    -> RequestBuilder<'t, response::HeaderOnly> {
+        self.push_path(&["droplets"]);
+        let body = DropletIds { droplet_ids: ids.to_vec() };
+        RequestBuilder {
+            method: method,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&body).unwrap()),
+        }
+    }
+    fn forwarding_rules_req(mut self,
+                            method: Method,
+                            rules: &[ForwardingRule])
+                            -> RequestBuilder<'t, response::HeaderOnly> {
+        self.push_path(&["forwarding_rules"]);
+        let body = ForwardingRules { forwarding_rules: rules.to_vec() };
+        RequestBuilder {
+            method: method,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&body).unwrap()),
+        }
+    }
+}
+
+impl<'t> DoRequest<response::LoadBalancer> for RequestBuilder<'t, response::LoadBalancer> {}
@@ -1,6 +1,19 @@
+pub use self::apps::LogType;
+pub use self::cdn_endpoints::{CdnEndpoint, CdnEndpointUpdate};
+pub use self::certificates::Certificate;
+pub use self::databases::{ConnectionPool, Database, DatabaseReplica};
 pub use self::droplets::Droplet;
 pub use self::dns::{DnsRecType, DnsRecord};
+pub use self::firewalls::Firewall;
+pub use self::kubernetes::{KubernetesCluster, NodePool};
+pub use self::load_balancers::LoadBalancer;
+pub use self::monitoring::{AlertPolicy, BandwidthDirection, BandwidthInterface, DropletMetric};
+pub use self::projects::Project;
 pub use self::request::RequestBuilder;
+pub use self::tags::TagResource;
+pub use self::uptime::{UptimeAlert, UptimeCheck};
+pub use self::volumes::Volume;
+pub use self::vpcs::Vpc;
 
 mod account;
 mod action;
@@ -13,3 +26,19 @@ mod request;
 mod regions;
 mod sizes;
 mod arrays;
+mod volumes;
+mod floating_ips;
+mod load_balancers;
+mod firewalls;
+mod tags;
+mod vpcs;
+mod projects;
+mod certificates;
+mod cdn_endpoints;
+mod databases;
+mod kubernetes;
+mod registry;
+mod monitoring;
+mod apps;
+mod uptime;
+mod billing;
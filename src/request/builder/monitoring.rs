@@ -0,0 +1,214 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+use serde_json;
+
+use response::{self, AlertTargets};
+use request::RequestBuilder;
+use request::DoRequest;
+
+/// The droplet metrics which can be queried through `DoManager::monitoring()`. Bandwidth is
+/// queried separately, as it also needs an interface and direction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DropletMetric {
+    Cpu,
+    MemoryTotal,
+    MemoryFree,
+    MemoryAvailable,
+    MemoryCached,
+    FilesystemSize,
+    FilesystemFree,
+    Load1,
+    Load5,
+    Load15,
+}
+
+impl DropletMetric {
+    fn path(&self) -> &'static str {
+        match *self {
+            DropletMetric::Cpu => "cpu",
+            DropletMetric::MemoryTotal => "memory_total",
+            DropletMetric::MemoryFree => "memory_free",
+            DropletMetric::MemoryAvailable => "memory_available",
+            DropletMetric::MemoryCached => "memory_cached",
+            DropletMetric::FilesystemSize => "filesystem_size",
+            DropletMetric::FilesystemFree => "filesystem_free",
+            DropletMetric::Load1 => "load_1",
+            DropletMetric::Load5 => "load_5",
+            DropletMetric::Load15 => "load_15",
+        }
+    }
+}
+
+/// The network interface of a droplet whose bandwidth is queried
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BandwidthInterface {
+    Public,
+    Private,
+}
+
+impl BandwidthInterface {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            BandwidthInterface::Public => "public",
+            BandwidthInterface::Private => "private",
+        }
+    }
+}
+
+/// The direction of the traffic whose bandwidth is queried
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BandwidthDirection {
+    Inbound,
+    Outbound,
+}
+
+impl BandwidthDirection {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            BandwidthDirection::Inbound => "inbound",
+            BandwidthDirection::Outbound => "outbound",
+        }
+    }
+}
+
+// type (true)          String      The metric the policy watches (i.e.
+// "v1/insights/droplet/cpu").
+// description (true)   String      A description of the policy.
+// compare (true)       String      "GreaterThan" or "LessThan".
+// value (true)         Number      The threshold the metric is compared to.
+// window (true)        String      "5m", "10m", "30m" or "1h".
+// entities (true)      Array       The IDs of the droplets the policy applies to.
+// tags (true)          Array       The droplet tags the policy applies to.
+// alerts (true)        Object      The email addresses and Slack channels to notify.
+// enabled (true)       Boolean     Whether the policy is active.
+#[derive(Serialize, Debug, Clone)]
+pub struct AlertPolicy {
+    #[serde(rename="type")]
+    pub policy_type: String,
+    pub description: String,
+    pub compare: String,
+    pub value: f64,
+    pub window: String,
+    pub entities: Vec<String>,
+    pub tags: Vec<String>,
+    pub alerts: AlertTargets,
+    pub enabled: bool,
+}
+
+impl AlertPolicy {
+    /// An enabled policy without any droplets, tags or notification targets
+    pub fn new(policy_type: &str,
+               description: &str,
+               compare: &str,
+               value: f64,
+               window: &str)
+               -> AlertPolicy {
+        AlertPolicy {
+            policy_type: policy_type.to_owned(),
+            description: description.to_owned(),
+            compare: compare.to_owned(),
+            value: value,
+            window: window.to_owned(),
+            entities: vec![],
+            tags: vec![],
+            alerts: AlertTargets::default(),
+            enabled: true,
+        }
+    }
+
+    /// Scopes the policy to `droplets`, in addition to any droplets already in `entities`
+    pub fn droplets(mut self, droplets: &[&response::Droplet]) -> AlertPolicy {
+        self.entities.extend(droplets.iter().map(|d| format!("{:.0}", d.id)));
+        self
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::Monitoring> {
+    pub fn droplet_metric(mut self,
+                          metric: DropletMetric,
+                          droplet_id: u64,
+                          start: u64,
+                          end: u64)
+                          -> RequestBuilder<'t, response::Metrics> {
+        // GET: "https://api.digitalocean.com/v2/monitoring/metrics/droplet/$METRIC?
+        //       host_id=$ID&start=$START&end=$END"
+        self.push_path(&["metrics", "droplet", metric.path()]);
+        self.set_query("host_id", &droplet_id.to_string()[..]);
+        self.set_query("start", &start.to_string()[..]);
+        self.set_query("end", &end.to_string()[..]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn droplet_bandwidth(mut self,
+                             droplet_id: u64,
+                             interface: BandwidthInterface,
+                             direction: BandwidthDirection,
+                             start: u64,
+                             end: u64)
+                             -> RequestBuilder<'t, response::Metrics> {
+        // GET: "https://api.digitalocean.com/v2/monitoring/metrics/droplet/bandwidth?
+        //       host_id=$ID&interface=public&direction=inbound&start=$START&end=$END"
+        self.push_path(&["metrics", "droplet", "bandwidth"]);
+        self.set_query("host_id", &droplet_id.to_string()[..]);
+        self.set_query("interface", interface.as_str());
+        self.set_query("direction", direction.as_str());
+        self.set_query("start", &start.to_string()[..]);
+        self.set_query("end", &end.to_string()[..]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn alert_policies(mut self) -> RequestBuilder<'t, response::AlertPolicies> {
+        // GET: "https://api.digitalocean.com/v2/monitoring/alerts"
+        self.push_path(&["alerts"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn alert_policy(mut self, uuid: &str) -> RequestBuilder<'t, response::AlertPolicy> {
+        // GET: "https://api.digitalocean.com/v2/monitoring/alerts/$UUID"
+        self.push_path(&["alerts", uuid]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::AlertPolicies> {
+    pub fn create(self, policy: &AlertPolicy) -> RequestBuilder<'t, response::AlertPolicy> {
+        // POST: "https://api.digitalocean.com/v2/monitoring/alerts"
+        // body: see request::AlertPolicy
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(policy).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::AlertPolicy> {
+    pub fn update(self, policy: &AlertPolicy) -> RequestBuilder<'t, response::AlertPolicy> {
+        // PUT: "https://api.digitalocean.com/v2/monitoring/alerts/$UUID"
+        // body: see request::AlertPolicy
+        RequestBuilder {
+            method: Method::Put,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(policy).unwrap()),
+        }
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/monitoring/alerts/$UUID"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+}
+
+impl<'t> DoRequest<response::Metrics> for RequestBuilder<'t, response::Metrics> {}
+
+impl<'t> DoRequest<response::AlertPolicy> for RequestBuilder<'t, response::AlertPolicy> {}
@@ -0,0 +1,112 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+use serde_json;
+
+use response;
+use request::RequestBuilder;
+use request::DoRequest;
+
+// name (true)      String      The human-readable name for the project. The maximum length is
+// 175 characters and the name must be unique.
+// purpose (true)   String      The purpose of the project (i.e. "Web Application").
+// description      String      The description of the project. The maximum length is 255
+// characters.
+// environment      String      The environment of the project's resources ("Development",
+// "Staging" or "Production").
+// is_default       Boolean     Only used when updating a project. If true, all resources will be
+// added to this project if no project is specified.
+#[derive(Serialize, Debug, Clone)]
+pub struct Project {
+    pub name: String,
+    pub purpose: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub environment: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub is_default: Option<bool>,
+}
+
+impl Project {
+    /// A project without a description or environment
+    pub fn new(name: &str, purpose: &str) -> Project {
+        Project {
+            name: name.to_owned(),
+            purpose: purpose.to_owned(),
+            description: None,
+            environment: None,
+            is_default: None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Resources {
+    resources: Vec<String>,
+}
+
+impl<'t> RequestBuilder<'t, response::Projects> {
+    pub fn create(self, project: &Project) -> RequestBuilder<'t, response::Project> {
+        // POST: "https://api.digitalocean.com/v2/projects"
+        // body: see request::Project
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(project).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::Project> {
+    pub fn update(self, project: &Project) -> RequestBuilder<'t, response::Project> {
+        // PUT: "https://api.digitalocean.com/v2/projects/$ID"
+        // body: see request::Project
+        RequestBuilder {
+            method: Method::Put,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(project).unwrap()),
+        }
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/projects/$ID"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+    pub fn resources(mut self) -> RequestBuilder<'t, response::ProjectResources> {
+        // GET: "https://api.digitalocean.com/v2/projects/$ID/resources"
+        self.push_path(&["resources"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn assign_resources(mut self,
+                            urns: &[&str])
+                            -> RequestBuilder<'t, response::ProjectResources> {
+        // POST: "https://api.digitalocean.com/v2/projects/$ID/resources"
+        // body:
+        //      "resources" : ["do:droplet:13457723", "do:domain:example.com"]
+        self.push_path(&["resources"]);
+        let body = Resources { resources: urns.iter().map(|u| (*u).to_owned()).collect() };
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&body).unwrap()),
+        }
+    }
+}
+
+impl<'t> DoRequest<response::Project> for RequestBuilder<'t, response::Project> {}
@@ -0,0 +1,164 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+use serde_json;
+
+use response;
+use request::RequestBuilder;
+use request::DoRequest;
+use Error;
+
+#[derive(Serialize)]
+struct NewRegistry<'a> {
+    name: &'a str,
+    subscription_tier_slug: &'a str,
+    #[serde(skip_serializing_if="Option::is_none")]
+    region: Option<&'a str>,
+}
+
+impl<'t> RequestBuilder<'t, response::Registry> {
+    pub fn create(self,
+                  name: &str,
+                  subscription_tier: &str,
+                  region: Option<&str>)
+                  -> RequestBuilder<'t, response::Registry> {
+        // POST: "https://api.digitalocean.com/v2/registry"
+        // body:
+        //      "name" : "example"                      // true
+        //      "subscription_tier_slug" : "basic"      // true ("starter", "basic" or
+        //                                                 "professional")
+        //      "region" : "fra1"
+        let body = NewRegistry {
+            name: name,
+            subscription_tier_slug: subscription_tier,
+            region: region,
+        };
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&body).unwrap()),
+        }
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/registry"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+    pub fn repositories(mut self, registry: &str) -> RequestBuilder<'t, response::Repositories> {
+        // GET: "https://api.digitalocean.com/v2/registry/$REGISTRY_NAME/repositories"
+        self.push_path(&[registry, "repositories"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn repository(mut self,
+                      registry: &str,
+                      repository: &str)
+                      -> RequestBuilder<'t, response::Repository> {
+        // "https://api.digitalocean.com/v2/registry/$REGISTRY_NAME/repositories/$REPOSITORY"
+        // Repository names containing "/" are percent-encoded as a single path segment
+        self.push_path(&[registry, "repositories", repository]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn garbage_collection(mut self,
+                              registry: &str)
+                              -> RequestBuilder<'t, response::GarbageCollection> {
+        // GET: "https://api.digitalocean.com/v2/registry/$REGISTRY_NAME/garbage-collection"
+        self.push_path(&[registry, "garbage-collection"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn garbage_collections(mut self,
+                               registry: &str)
+                               -> RequestBuilder<'t, response::GarbageCollections> {
+        // GET: "https://api.digitalocean.com/v2/registry/$REGISTRY_NAME/garbage-collections"
+        self.push_path(&[registry, "garbage-collections"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn docker_credentials(mut self) -> RequestBuilder<'t, response::DockerCredentials> {
+        // GET: "https://api.digitalocean.com/v2/registry/docker-credentials"
+        self.push_path(&["docker-credentials"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::Repository> {
+    pub fn tags(mut self) -> RequestBuilder<'t, response::RepositoryTags> {
+        // GET: "https://api.digitalocean.com/v2/registry/$REGISTRY_NAME/repositories/
+        //       $REPOSITORY/tags"
+        self.push_path(&["tags"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn delete_tag(self, tag: &str) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/registry/$REGISTRY_NAME/repositories/
+        //          $REPOSITORY/tags/$TAG"
+        self.delete_path(&["tags", tag])
+    }
+    pub fn delete_manifest(self, digest: &str) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/registry/$REGISTRY_NAME/repositories/
+        //          $REPOSITORY/digests/$MANIFEST_DIGEST"
+        self.delete_path(&["digests", digest])
+    }
+
+    fn delete_path(mut self, path: &[&str]) -> RequestBuilder<'t, response::HeaderOnly> {
+        self.push_path(path);
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::GarbageCollection> {
+    pub fn start(self) -> RequestBuilder<'t, response::GarbageCollection> {
+        // POST: "https://api.digitalocean.com/v2/registry/$REGISTRY_NAME/garbage-collection"
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::DockerCredentials> {
+    pub fn read_write(mut self,
+                      read_write: bool)
+                      -> RequestBuilder<'t, response::DockerCredentials> {
+        // GET: "https://api.digitalocean.com/v2/registry/docker-credentials?read_write=true"
+        self.set_query("read_write", if read_write { "true" } else { "false" });
+        self
+    }
+    pub fn expiry_seconds(mut self,
+                          seconds: u64)
+                          -> RequestBuilder<'t, response::DockerCredentials> {
+        // GET: "https://api.digitalocean.com/v2/registry/docker-credentials?expiry_seconds=3600"
+        self.set_query("expiry_seconds", &seconds.to_string()[..]);
+        self
+    }
+}
+
+impl<'t> DoRequest<response::Registry> for RequestBuilder<'t, response::Registry> {}
+
+impl<'t> DoRequest<response::GarbageCollection>
+    for RequestBuilder<'t, response::GarbageCollection> {}
+
+impl<'t> DoRequest<response::DockerCredentials>
+    for RequestBuilder<'t, response::DockerCredentials> {
+    fn retrieve(&self) -> Result<response::DockerCredentials, Error> {
+        // The credentials are a docker config, rather than wrapped in a "docker_credentials" key
+        response::DockerCredentials::parse(try!(self.retrieve_json()))
+    }
+}
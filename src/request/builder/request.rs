@@ -1,42 +1,71 @@
 use std::marker::PhantomData;
 use std::fmt;
+use std::sync::Arc;
 
 use serde::Deserialize;
-use serde_json;
 use hyper::method::Method;
+use url::Url;
 
 use response::{self, NamedResponse, NotArray, RawPagedResponse};
 use request::{BaseRequest, DoRequest};
 use request::PagedRequest;
+use transport::Transport;
+use Error;
 
 pub struct RequestBuilder<'t, T> {
     pub auth: &'t str,
+    pub transport: Arc<Transport>,
     pub method: Method,
-    pub url: String,
+    pub url: Url,
     pub resp_t: PhantomData<*const T>,
     pub body: Option<String>,
 }
 
 impl<'t, T> RequestBuilder<'t, T> {
-    pub fn with_auth(auth: &'t str) -> RequestBuilder<'t, T> {
+    pub fn new(auth: &'t str, transport: Arc<Transport>, url: Url) -> RequestBuilder<'t, T> {
         RequestBuilder {
             auth: auth,
+            transport: transport,
             method: Method::Get,
-            url: String::new(),
+            url: url,
             resp_t: PhantomData,
             body: None,
         }
     }
-    pub fn new<S>(auth: &'t str, url: S) -> RequestBuilder<'t, T>
-        where S: Into<String>
-    {
-        RequestBuilder {
-            auth: auth,
-            method: Method::Get,
-            url: url.into(),
-            resp_t: PhantomData,
-            body: None,
-        }
+
+    /// Appends `segments` to the path of the URL, leaving any query string intact. Each segment
+    /// is percent-encoded, so it may safely contain user supplied values such as IDs or names.
+    pub fn push_path(&mut self, segments: &[&str]) {
+        self.url
+            .path_segments_mut()
+            .expect("DigitalOcean URLs always have a path")
+            .pop_if_empty()
+            .extend(segments);
+    }
+
+    /// Replaces the last segment of the URL path with `segments`, i.e. turning `/v2/account`
+    /// into `/v2/actions`
+    pub fn replace_path(&mut self, segments: &[&str]) {
+        self.url
+            .path_segments_mut()
+            .expect("DigitalOcean URLs always have a path")
+            .pop_if_empty()
+            .pop()
+            .extend(segments);
+    }
+
+    /// Sets the query parameter `key` to `value`, replacing any previous value of `key`
+    pub fn set_query(&mut self, key: &str, value: &str) {
+        let pairs: Vec<(String, String)> = self.url
+                                               .query_pairs()
+                                               .into_owned()
+                                               .filter(|&(ref k, _)| k != key)
+                                               .collect();
+        self.url
+            .query_pairs_mut()
+            .clear()
+            .extend_pairs(pairs)
+            .append_pair(key, value);
     }
 }
 
@@ -50,11 +79,7 @@ impl<'t, T> fmt::Display for RequestBuilder<'t, T> {
                 body: {}\n",
                self.method,
                self.auth,
-               if !self.url.is_empty() {
-                   &self.url
-               } else {
-                   "None"
-               },
+               self.url,
                if let Some(ref bdy) = self.body {
                    bdy
                } else {
@@ -65,107 +90,71 @@ impl<'t, T> fmt::Display for RequestBuilder<'t, T> {
 
 impl<'t, T> BaseRequest for RequestBuilder<'t, T> {
     fn auth(&self) -> &str { self.auth }
-    fn url(&self) -> &str { &self.url[..] }
+    fn url(&self) -> &str { self.url.as_str() }
     fn method(&self) -> Method { self.method.clone() }
     fn body(&self) -> Option<String> { self.body.clone() }
+    fn transport(&self) -> &Transport { &*self.transport }
 }
 
 // Can't use because of impl for DoRequest<Vec<T>>, waiting on negative trait
 // bounds
 // impl<'t, T: !Iterator> DoRequest<T> for RequestBuilder<'t, T> { }
 
+impl<'t, I> RequestBuilder<'t, Vec<I>> {
+    /// Sets the number of items requested per page (DigitalOcean allows up to `200`, and
+    /// defaults to `20`)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # let domgr = DoManager::with_token("asfasdfasdf");
+    /// // Retrieves all images using as few requests as possible
+    /// match domgr.images().per_page(200).retrieve() {
+    ///     Ok(images) => println!("Images: {}", images.len()),
+    ///     Err(e)     => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn per_page(mut self, n: u32) -> RequestBuilder<'t, Vec<I>> {
+        self.set_query("per_page", &n.to_string()[..]);
+        self
+    }
+}
+
 impl<'t, I> PagedRequest for RequestBuilder<'t, Vec<I>>
     where I: Deserialize + NamedResponse + NotArray
 {
     type Item = I;
-    fn retrieve_single_page(&self, url: String) -> Result<RawPagedResponse<I>, String> {
+    fn retrieve_single_page(&self, url: String) -> Result<RawPagedResponse<I>, Error> {
         debug!("Inside retrieve_single_page() with url: {}", &url[..]);
-        if url.is_empty() {
-            debug!("No url provided");
-            return Err("No URL provided".to_owned());
-        }
-        let mut rb: RequestBuilder<'t, Vec<I>> = RequestBuilder::with_auth(self.auth);
-        rb.url = url.clone();
-        match rb.retrieve_json() {
-            Ok(ref s) => {
-                // FIXME \/ \/
-                let mut name = <I as NamedResponse>::name().into_owned();
-                name.push('s');
-                let re = regex!(&format!("\"{}\"", name));
-                let json_str = &re.replace(&s[..], "\"collection\"");
-                match serde_json::from_str::<response::RawPagedResponse<I>>(json_str) {
-                    // FIXME ^^
-                    Ok(val) => Ok(val),
-                    Err(e) => {
-                        debug!("Error getting objects: {}", e.to_string());
-                        Err(e.to_string())
-                    }
-                }
-            }
-            Err(e) => {
-                debug!("Error getting json: {}", e.to_string());
-                Err(e.to_string())
-            }
-        }
+        let url = try!(Url::parse(&url[..]));
+        let rb: RequestBuilder<'t, Vec<I>> =
+            RequestBuilder::new(self.auth, self.transport.clone(), url);
+        let s = try!(rb.retrieve_json());
+        RawPagedResponse::from_json(&s[..])
+    }
+    fn retrieve_first_page(&self) -> Result<RawPagedResponse<I>, Error> {
+        let s = try!(self.retrieve_json());
+        RawPagedResponse::from_json(&s[..])
     }
 }
 
 impl<'t, I> DoRequest<Vec<I>> for RequestBuilder<'t, Vec<I>>
     where I: Deserialize + NamedResponse + NotArray
 {
-    #[cfg_attr(not(feature = "debug"), allow(unused_variables))]
-    fn retrieve(&self) -> Result<Vec<I>, String> {
-        debug!("Inside retrieve() for  paged request");
-        debug!("Getting json...");
-        match self.retrieve_json() {
-            Ok(ref s) => {
-                debug!("Sucess");
-                // FIXME \/ \/
-                let mut name = <I as NamedResponse>::name().into_owned();
-                name.push('s');
-                debug!("Replacing {} with collection", &name[..]);
-                let re = regex!(&format!("\"{}\"", name));
-                let res = &re.replace(&s[..], "\"collection\"");
-                debug!("Getting object from json string");
-                match serde_json::from_str::<response::RawPagedResponse<I>>(res) {
-                    // FIXME ^^
-                    Ok(mut val) => {
-                        debug!("Sucess");
-                        let mut regs = vec![];
-                        regs.append(&mut val.collection);
-                        let mut url = if val.links.pages.is_some() &&
-                                         val.links.pages.clone().unwrap().next.is_some() {
-                            val.links.pages.clone().unwrap().next.clone().unwrap()
-                        } else {
-                            String::new()
-                        };
-                        debug!("Next page URL: {}", &url[..]);
-                        while let Ok(mut page) = self.retrieve_single_page(url.clone()) {
-                            regs.append(&mut page.collection);
-                            url = if page.links.pages.is_some() &&
-                                     page.links.pages.clone().unwrap().next.is_some() {
-                                page.links.pages.clone().unwrap().next.clone().unwrap()
-                            } else {
-                                String::new()
-                            };
-                            debug!("Next page URL: {}", &url[..]);
-                        }
-                        Ok(regs)
-                    }
-                    Err(e) => {
-                        debug!("Error getting object: {}", e.to_string());
-                        self.retrieve_obj(name)
-                    }
-                }
-            }
-            Err(e) => {
-                debug!("Error getting json: {}", e.to_string());
-                Err(e.to_string())
-            }
+    fn retrieve(&self) -> Result<Vec<I>, Error> {
+        debug!("Inside retrieve() for paged request");
+        // Lists which aren't paginated (i.e. droplet neighbors) are parsed as a single page, so
+        // the request is only ever sent once
+        let mut regs = vec![];
+        for page in self.pages() {
+            regs.append(&mut try!(page).collection);
         }
+        Ok(regs)
     }
 }
 
 impl<'t> DoRequest<response::HeaderOnly> for RequestBuilder<'t, response::HeaderOnly> {
-    fn retrieve(&self) -> Result<response::HeaderOnly, String> { self.retrieve_header() }
+    fn retrieve(&self) -> Result<response::HeaderOnly, Error> { self.retrieve_header() }
 }
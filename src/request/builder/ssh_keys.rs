@@ -15,6 +15,7 @@ impl<'t> RequestBuilder<'t, response::SshKeys> {
         RequestBuilder {
             method: Method::Post,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: Some(format!("{{\"name\":{:?},\"public_key\":{:?}}}", name, pub_key)),
@@ -33,6 +34,7 @@ impl<'t> RequestBuilder<'t, response::SshKey> {
             method: Method::Put,
             url: self.url,
             auth: self.auth,
+            transport: self.transport,
             resp_t: PhantomData,
             body: Some(format!("{{\"name\":{:?}}}", name)),
         }
@@ -44,6 +46,7 @@ impl<'t> RequestBuilder<'t, response::SshKey> {
         RequestBuilder {
             method: Method::Delete,
             auth: self.auth,
+            transport: self.transport,
             url: self.url,
             resp_t: PhantomData,
             body: None,
@@ -0,0 +1,98 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+use serde_json;
+
+use response;
+use request::RequestBuilder;
+use request::DoRequest;
+
+// resource_id (true)   String      The identifier of a resource (i.e. a Droplet ID).
+// resource_type (true) String      The type of the resource ("droplet", "image", "volume" or
+// "volume_snapshot").
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct TagResource {
+    pub resource_id: String,
+    pub resource_type: String,
+}
+
+impl TagResource {
+    pub fn new(resource_type: &str, id: &str) -> TagResource {
+        TagResource {
+            resource_id: id.to_owned(),
+            resource_type: resource_type.to_owned(),
+        }
+    }
+
+    /// The droplet with `id`
+    pub fn droplet(id: &str) -> TagResource { TagResource::new("droplet", id) }
+}
+
+#[derive(Serialize)]
+struct Resources {
+    resources: Vec<TagResource>,
+}
+
+impl<'t> RequestBuilder<'t, response::Tags> {
+    pub fn create(self, name: &str) -> RequestBuilder<'t, response::Tag> {
+        // POST: "https://api.digitalocean.com/v2/tags"
+        // body:
+        //      "name" : "awesome"
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(format!("{{\"name\":{:?}}}", name)),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::Tag> {
+    pub fn tag_resources(self,
+                         resources: &[TagResource])
+                         -> RequestBuilder<'t, response::HeaderOnly> {
+        // POST: "https://api.digitalocean.com/v2/tags/$TAG_NAME/resources"
+        // body:
+        //      "resources" : [{ "resource_id" : "9569411", "resource_type" : "droplet" }]
+        self.resources(Method::Post, resources)
+    }
+    pub fn untag_resources(self,
+                           resources: &[TagResource])
+                           -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/tags/$TAG_NAME/resources"
+        // body:
+        //      "resources" : [{ "resource_id" : "9569411", "resource_type" : "droplet" }]
+        self.resources(Method::Delete, resources)
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/tags/$TAG_NAME"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+
+    fn resources(mut self,
+                 method: Method,
+                 resources: &[TagResource])
+                 -> RequestBuilder<'t, response::HeaderOnly> {
+        self.push_path(&["resources"]);
+        let body = Resources { resources: resources.to_vec() };
+        RequestBuilder {
+            method: method,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&body).unwrap()),
+        }
+    }
+}
+
+impl<'t> DoRequest<response::Tag> for RequestBuilder<'t, response::Tag> {}
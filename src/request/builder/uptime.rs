@@ -0,0 +1,192 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+use serde_json;
+
+use response::{self, AlertTargets};
+use request::RequestBuilder;
+use request::DoRequest;
+
+// name (true)      String      A human-readable name for the check.
+// type (true)      String      "ping", "http" or "https".
+// target (true)    String      The endpoint to check (a host name for "ping", otherwise a URL).
+// regions (true)   Array       The regions to run the check from ("us_east", "us_west",
+// "eu_west" or "se_asia").
+// enabled          Boolean     Whether the check is running, defaults to true.
+#[derive(Serialize, Debug, Clone)]
+pub struct UptimeCheck {
+    pub name: String,
+    #[serde(rename="type")]
+    pub check_type: String,
+    pub target: String,
+    pub regions: Vec<String>,
+    pub enabled: bool,
+}
+
+impl UptimeCheck {
+    /// An enabled check of `target` run from `regions`
+    pub fn new(name: &str, check_type: &str, target: &str, regions: &[&str]) -> UptimeCheck {
+        UptimeCheck {
+            name: name.to_owned(),
+            check_type: check_type.to_owned(),
+            target: target.to_owned(),
+            regions: regions.iter().map(|r| (*r).to_owned()).collect(),
+            enabled: true,
+        }
+    }
+}
+
+// name (true)      String      A human-readable name for the alert.
+// type (true)      String      "latency", "down", "down_global" or "ssl_expiry".
+// notifications (true) Object  The email addresses and Slack channels to notify.
+// threshold        Number      The value the alert triggers at (milliseconds for "latency",
+// days for "ssl_expiry").
+// comparison       String      "greater_than" or "less_than".
+// period           String      How long the threshold has to be crossed for ("2m", "3m", "5m",
+// "10m", "15m", "30m" or "1h").
+#[derive(Serialize, Debug, Clone)]
+pub struct UptimeAlert {
+    pub name: String,
+    #[serde(rename="type")]
+    pub alert_type: String,
+    pub notifications: AlertTargets,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub threshold: Option<u64>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub comparison: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub period: Option<String>,
+}
+
+impl UptimeAlert {
+    /// An alert without a threshold (i.e. of type "down") or anyone to notify
+    pub fn new(name: &str, alert_type: &str) -> UptimeAlert {
+        UptimeAlert {
+            name: name.to_owned(),
+            alert_type: alert_type.to_owned(),
+            notifications: AlertTargets::default(),
+            threshold: None,
+            comparison: None,
+            period: None,
+        }
+    }
+
+    /// An alert triggering once the value is `comparison` `threshold` for `period`
+    pub fn threshold(name: &str,
+                     alert_type: &str,
+                     comparison: &str,
+                     threshold: u64,
+                     period: &str)
+                     -> UptimeAlert {
+        UptimeAlert {
+            threshold: Some(threshold),
+            comparison: Some(comparison.to_owned()),
+            period: Some(period.to_owned()),
+            ..UptimeAlert::new(name, alert_type)
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::UptimeChecks> {
+    pub fn create(self, check: &UptimeCheck) -> RequestBuilder<'t, response::UptimeCheck> {
+        // POST: "https://api.digitalocean.com/v2/uptime/checks"
+        // body: see request::UptimeCheck
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(check).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::UptimeCheck> {
+    pub fn update(self, check: &UptimeCheck) -> RequestBuilder<'t, response::UptimeCheck> {
+        // PUT: "https://api.digitalocean.com/v2/uptime/checks/$ID"
+        // body: see request::UptimeCheck
+        RequestBuilder {
+            method: Method::Put,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(check).unwrap()),
+        }
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/uptime/checks/$ID"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+    pub fn state(mut self) -> RequestBuilder<'t, response::UptimeCheckState> {
+        // GET: "https://api.digitalocean.com/v2/uptime/checks/$ID/state"
+        self.push_path(&["state"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn alerts(mut self) -> RequestBuilder<'t, response::UptimeAlerts> {
+        // GET: "https://api.digitalocean.com/v2/uptime/checks/$ID/alerts"
+        self.push_path(&["alerts"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn alert(mut self, id: &str) -> RequestBuilder<'t, response::UptimeAlert> {
+        // GET: "https://api.digitalocean.com/v2/uptime/checks/$ID/alerts/$ALERT_ID"
+        self.push_path(&["alerts", id]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::UptimeAlerts> {
+    pub fn create(self, alert: &UptimeAlert) -> RequestBuilder<'t, response::UptimeAlert> {
+        // POST: "https://api.digitalocean.com/v2/uptime/checks/$ID/alerts"
+        // body: see request::UptimeAlert
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(alert).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::UptimeAlert> {
+    pub fn update(self, alert: &UptimeAlert) -> RequestBuilder<'t, response::UptimeAlert> {
+        // PUT: "https://api.digitalocean.com/v2/uptime/checks/$ID/alerts/$ALERT_ID"
+        // body: see request::UptimeAlert
+        RequestBuilder {
+            method: Method::Put,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(alert).unwrap()),
+        }
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/uptime/checks/$ID/alerts/$ALERT_ID"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+}
+
+impl<'t> DoRequest<response::UptimeCheck> for RequestBuilder<'t, response::UptimeCheck> {}
+
+impl<'t> DoRequest<response::UptimeCheckState>
+    for RequestBuilder<'t, response::UptimeCheckState> {}
+
+impl<'t> DoRequest<response::UptimeAlert> for RequestBuilder<'t, response::UptimeAlert> {}
@@ -0,0 +1,185 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+use serde_json;
+
+use response;
+use request::RequestBuilder;
+use request::DoRequest;
+
+// size_gigabytes (true) number  The size of the Block Storage volume in GiB (1024^3).
+// name (true)      String      A human-readable name for the Block Storage volume. Must be
+// lowercase and be composed only of numbers, letters and "-", up to a limit of 64 characters.
+// description      String      An optional free-form text field to describe a Block Storage
+// volume.
+// region           String      The region where the Block Storage volume will be created.
+// Required unless `snapshot_id` is given.
+// snapshot_id      String      The unique identifier for the volume snapshot from which to
+// create the volume.
+// filesystem_type  String      The name of the filesystem type to be used on the volume
+// ("ext4" or "xfs"). When provided, the volume will automatically be formatted.
+// filesystem_label String      The label to be applied to the filesystem.
+#[derive(Serialize)]
+pub struct Volume {
+    pub name: String,
+    pub size_gigabytes: u64,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub snapshot_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub filesystem_type: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub filesystem_label: Option<String>,
+}
+
+impl fmt::Display for Volume {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Name: {}\n\
+                Size: {} GB\n\
+                Description: {}\n\
+                Region: {}\n\
+                Snapshot ID: {}\n\
+                Filesystem Type: {}\n\
+                Filesystem Label: {}\n",
+               self.name,
+               self.size_gigabytes,
+               self.description.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.region.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.snapshot_id.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.filesystem_type.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.filesystem_label.as_ref().map(|s| &s[..]).unwrap_or("None"))
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::Volumes> {
+    pub fn region(mut self, region: &str) -> RequestBuilder<'t, response::Volumes> {
+        // GET: "https://api.digitalocean.com/v2/volumes?region=$REGION"
+        self.set_query("region", region);
+        self
+    }
+    pub fn name(mut self, name: &str) -> RequestBuilder<'t, response::Volumes> {
+        // GET: "https://api.digitalocean.com/v2/volumes?name=$NAME"
+        self.set_query("name", name);
+        self
+    }
+    pub fn create(self, volume: &Volume) -> RequestBuilder<'t, response::Volume> {
+        // POST: "https://api.digitalocean.com/v2/volumes"
+        // body:
+        //      "size_gigabytes" : 10           // true
+        //      "name" : "example"              // true
+        //      "description" : ""
+        //      "region" : "nyc1"
+        //      "snapshot_id" : ""
+        //      "filesystem_type" : "ext4"
+        //      "filesystem_label" : ""
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(volume).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::Volume> {
+    pub fn attach(mut self, droplet_id: u64) -> RequestBuilder<'t, response::Action> {
+        // POST: "https://api.digitalocean.com/v2/volumes/$ID/actions"
+        // body:
+        //      "type" : "attach"
+        //      "droplet_id" : 1234
+        self.push_path(&["actions"]);
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(format!("{{\"type\":\"attach\",\"droplet_id\":{}}}", droplet_id)),
+        }
+    }
+    pub fn detach(mut self, droplet_id: u64) -> RequestBuilder<'t, response::Action> {
+        // POST: "https://api.digitalocean.com/v2/volumes/$ID/actions"
+        // body:
+        //      "type" : "detach"
+        //      "droplet_id" : 1234
+        self.push_path(&["actions"]);
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(format!("{{\"type\":\"detach\",\"droplet_id\":{}}}", droplet_id)),
+        }
+    }
+    pub fn resize(mut self, size_gigabytes: u64, region: &str)
+                  -> RequestBuilder<'t, response::Action> {
+        // POST: "https://api.digitalocean.com/v2/volumes/$ID/actions"
+        // body:
+        //      "type" : "resize"
+        //      "size_gigabytes" : 100
+        //      "region" : "nyc1"
+        self.push_path(&["actions"]);
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(format!("{{\"type\":\"resize\",\"size_gigabytes\":{},\"region\":{:?}}}",
+                               size_gigabytes,
+                               region)),
+        }
+    }
+    pub fn actions(mut self) -> RequestBuilder<'t, response::Actions> {
+        // GET: "https://api.digitalocean.com/v2/volumes/$ID/actions"
+        self.push_path(&["actions"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn action(mut self, id: &str) -> RequestBuilder<'t, response::Action> {
+        // GET: "https://api.digitalocean.com/v2/volumes/$ID/actions/$ACTION_ID"
+        self.push_path(&["actions", id]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn snapshots(mut self) -> RequestBuilder<'t, response::VolumeSnapshots> {
+        // GET: "https://api.digitalocean.com/v2/volumes/$ID/snapshots"
+        self.push_path(&["snapshots"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+    pub fn snapshot(mut self, name: &str) -> RequestBuilder<'t, response::VolumeSnapshot> {
+        // POST: "https://api.digitalocean.com/v2/volumes/$ID/snapshots"
+        // body:
+        //      "name" : "big-data-snapshot"
+        self.push_path(&["snapshots"]);
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(format!("{{\"name\":{:?}}}", name)),
+        }
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/volumes/$ID"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+}
+
+impl<'t> DoRequest<response::Volume> for RequestBuilder<'t, response::Volume> {}
+
+impl<'t> DoRequest<response::VolumeSnapshot> for RequestBuilder<'t, response::VolumeSnapshot> {}
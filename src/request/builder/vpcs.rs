@@ -0,0 +1,115 @@
+use std::marker::PhantomData;
+
+use hyper::method::Method;
+use serde_json;
+
+use response;
+use request::RequestBuilder;
+use request::DoRequest;
+
+// name (true)      String      The name of the VPC. Must be unique and may only contain
+// alphanumeric characters, dashes, and periods.
+// region (true)    String      The slug identifier for the region where the VPC will be created.
+// description      String      A free-form text field for describing the VPC.
+// ip_range         String      The range of IP addresses in the VPC in CIDR notation. Network
+// ranges cannot overlap with other networks in the same account and must be in range of private
+// addresses as defined in RFC1918. If excluded, a range will be selected automatically.
+#[derive(Serialize, Debug, Clone)]
+pub struct Vpc {
+    pub name: String,
+    pub region: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub ip_range: Option<String>,
+}
+
+impl Vpc {
+    /// A VPC with an automatically selected IP range and no description
+    pub fn new(name: &str, region: &str) -> Vpc {
+        Vpc {
+            name: name.to_owned(),
+            region: region.to_owned(),
+            description: None,
+            ip_range: None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct VpcUpdate<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if="Option::is_none")]
+    description: Option<&'a str>,
+}
+
+impl<'t> RequestBuilder<'t, response::Vpcs> {
+    pub fn create(self, vpc: &Vpc) -> RequestBuilder<'t, response::Vpc> {
+        // POST: "https://api.digitalocean.com/v2/vpcs"
+        // body:
+        //      "name" : "env.prod-vpc"             // true
+        //      "region" : "nyc1"                   // true
+        //      "description" : ""
+        //      "ip_range" : "10.10.10.0/24"
+        RequestBuilder {
+            method: Method::Post,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(vpc).unwrap()),
+        }
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::Vpc> {
+    pub fn update(self,
+                  name: &str,
+                  description: Option<&str>)
+                  -> RequestBuilder<'t, response::Vpc> {
+        // PUT: "https://api.digitalocean.com/v2/vpcs/$ID"
+        // body:
+        //      "name" : "env.prod-vpc"             // true
+        //      "description" : ""
+        let body = VpcUpdate {
+            name: name,
+            description: description,
+        };
+        RequestBuilder {
+            method: Method::Put,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: Some(serde_json::to_string(&body).unwrap()),
+        }
+    }
+    pub fn delete(self) -> RequestBuilder<'t, response::HeaderOnly> {
+        // DELETE: "https://api.digitalocean.com/v2/vpcs/$ID"
+        RequestBuilder {
+            method: Method::Delete,
+            auth: self.auth,
+            transport: self.transport,
+            url: self.url,
+            resp_t: PhantomData,
+            body: None,
+        }
+    }
+    pub fn members(mut self) -> RequestBuilder<'t, response::VpcMembers> {
+        // GET: "https://api.digitalocean.com/v2/vpcs/$ID/members"
+        self.push_path(&["members"]);
+        RequestBuilder::new(self.auth, self.transport, self.url)
+    }
+}
+
+impl<'t> RequestBuilder<'t, response::VpcMembers> {
+    pub fn resource_type(mut self,
+                         resource_type: &str)
+                         -> RequestBuilder<'t, response::VpcMembers> {
+        // GET: "https://api.digitalocean.com/v2/vpcs/$ID/members?resource_type=$TYPE"
+        self.set_query("resource_type", resource_type);
+        self
+    }
+}
+
+impl<'t> DoRequest<response::Vpc> for RequestBuilder<'t, response::Vpc> {}
@@ -1,134 +1,102 @@
-use std::io::{Read, Write};
-
-use hyper::client;
-use hyper::{self, Error, Url};
 use hyper::method::Method;
-use hyper::net::Fresh;
-use hyper::header::{Authorization, ContentType};
 
 use serde_json::{self, Value};
-use serde::de::Deserialize;
+use serde::de::{Deserialize, Error as DeError};
 
-use response::{self, DoError, NamedResponse};
+use response::{self, NamedResponse};
+use error::{self, Error};
+use transport::{HttpResponse, Transport};
 
 pub trait BaseRequest {
     fn url(&self) -> &str;
     fn auth(&self) -> &str;
     fn method(&self) -> Method;
     fn body(&self) -> Option<String>;
+    fn transport(&self) -> &Transport;
 }
 
 pub trait DoRequest<T>: BaseRequest
     where T: Deserialize + NamedResponse
 {
-    fn request(&self) -> hyper::Result<client::Request<Fresh>> {
-        let url = match Url::parse(self.url()) {
-            Ok(url) => url,
-            Err(e) => return Err(Error::Uri(e)),
-        };
-        let mut fresh_req = match client::Request::new(self.method(), url) {
-            Ok(req) => req,
-            Err(e) => return Err(e),
-        };
+    fn headers(&self) -> Vec<(String, String)> {
         let mut auth_s = String::new();
         auth_s.push_str("Bearer ");
         auth_s.push_str(self.auth());
-        fresh_req.headers_mut().set(ContentType("application/json".parse().unwrap()));
-        fresh_req.headers_mut().set(Authorization(auth_s));
-        Ok(fresh_req)
+        vec![("Content-Type".to_owned(), "application/json".to_owned()),
+             ("Authorization".to_owned(), auth_s)]
     }
 
-    fn retrieve_json(&self) -> hyper::Result<String> {
-        let url = match Url::parse(self.url()) {
-            Ok(url) => url,
-            Err(e) => return Err(Error::Uri(e)),
-        };
-        let mut fresh_req = match client::Request::new(self.method(), url) {
-            Ok(req) => req,
-            Err(e) => return Err(e),
-        };
-        let mut auth_s = String::new();
-        auth_s.push_str("Bearer ");
-        auth_s.push_str(self.auth());
-        fresh_req.headers_mut().set(ContentType("application/json".parse().unwrap()));
-        fresh_req.headers_mut().set(Authorization(auth_s));
-        let mut streaming_req = try!(fresh_req.start());
-        if let Some(ref b) = self.body() {
-            streaming_req.write(b.as_bytes()).unwrap();
-        }
-        let mut response = try!(streaming_req.send());
-        let mut s = String::new();
-        try!(response.read_to_string(&mut s));
-        Ok(s)
+    fn retrieve_raw_response(&self) -> Result<HttpResponse, Error> {
+        let body = self.body();
+        self.transport().send(self.method(),
+                              self.url(),
+                              &self.headers()[..],
+                              body.as_ref().map(|b| b.as_bytes()))
     }
 
-    fn retrieve_raw_response(&self) -> hyper::Result<client::response::Response> {
-        let url = match Url::parse(self.url()) {
-            Ok(url) => url,
-            Err(e) => return Err(Error::Uri(e)),
-        };
-        let mut fresh_req = match client::Request::new(self.method(), url) {
-            Ok(req) => req,
-            Err(e) => return Err(e),
-        };
-        let mut auth_s = String::new();
-        auth_s.push_str("Bearer ");
-        auth_s.push_str(self.auth());
-        fresh_req.headers_mut().set(ContentType("application/json".parse().unwrap()));
-        fresh_req.headers_mut().set(Authorization(auth_s));
-        let mut streaming_req = try!(fresh_req.start());
-        if let Some(ref b) = self.body() {
-            streaming_req.write(b.as_bytes()).unwrap();
+    fn retrieve_json(&self) -> Result<String, Error> {
+        self.retrieve_success().map(|r| r.body_string())
+    }
+
+    /// Performs the request, turning a non-success response into the appropriate error
+    fn retrieve_success(&self) -> Result<HttpResponse, Error> {
+        let response = try!(self.retrieve_raw_response());
+        if !response.is_success() {
+            let s = response.body_string();
+            debug!("Error response: {} {}", response.status, &s[..]);
+            return Err(Error::from_status(response.status,
+                                          &s[..],
+                                          header_f64(&response, "ratelimit-limit"),
+                                          header_f64(&response, "ratelimit-reset")));
         }
-        let response = try!(streaming_req.send());
         Ok(response)
     }
 
-    fn retrieve_header(&self) -> Result<response::HeaderOnly, String> {
+    fn retrieve_header(&self) -> Result<response::HeaderOnly, Error> {
         debug!("Inside retrieve_header()");
         debug!("Getting raw response...");
-        match self.retrieve_raw_response() {
-            Ok(resp) => {
-                let header = try!(response::HeaderOnly::from_response(resp));
-                Ok(header)
-            }
-            Err(e) => {
-                debug!("Error getting json: {}", e.to_string());
-                Err(e.to_string())
-            }
+        let resp = try!(self.retrieve_raw_response());
+        if !resp.is_success() {
+            debug!("Error response: {}", resp.status);
+            return Err(Error::from_status(resp.status,
+                                          &resp.body_string()[..],
+                                          header_f64(&resp, "ratelimit-limit"),
+                                          header_f64(&resp, "ratelimit-reset")));
         }
+        response::HeaderOnly::from_response(&resp)
     }
 
-    fn retrieve_obj(&self, obj: String) -> Result<T, String> {
+    fn retrieve_obj(&self, obj: String) -> Result<T, Error> {
         debug!("inside retrieve_obj() for regular type");
-        match self.retrieve_json() {
-            Ok(ref s) => {
-                match serde_json::from_str::<Value>(s) {
-                    Ok(ob) => {
-                        match ob.find(&obj) {
-                            Some(t) => {
-                                match serde_json::from_value(t.clone()) {
-                                    Ok(t) => Ok(t),
-                                    Err(e) => Err(e.to_string()),
-                                }
-                            }
-                            None => {
-                                match serde_json::from_value::<DoError>(ob.clone()) {
-                                    Ok(err) => Err(err.to_string()),
-                                    Err(e) => Err(e.to_string()),
-                                }
-                            }
-                        }
-                    }
-                    Err(e) => Err(e.to_string()),
-                }
+        let s = try!(self.retrieve_json());
+        let mut ob = try!(serde_json::from_str::<Value>(&s[..]));
+        let path = format!("/{}", obj);
+        match ob.as_object_mut().and_then(|m| m.remove(&obj)) {
+            Some(t) => error::decode(t, &path[..]),
+            None => {
+                debug!("Key '{}' missing from response", &obj[..]);
+                Err(Error::Deserialize {
+                    path: path,
+                    cause: serde_json::Error::custom(format!("missing field `{}`", obj)),
+                })
             }
-            Err(e) => Err(e.to_string()),
         }
     }
 
-    fn retrieve(&self) -> Result<T, String> {
+    /// Deserializes the whole body as `T`, for the endpoints whose response isn't wrapped in an
+    /// object keyed by `T::name()`
+    fn retrieve_unwrapped(&self) -> Result<T, Error> {
+        debug!("inside retrieve_unwrapped()");
+        let s = try!(self.retrieve_json());
+        Ok(try!(serde_json::from_str(&s[..])))
+    }
+
+    fn retrieve(&self) -> Result<T, Error> {
         debug!("Inside retrieve() for regular type");
         self.retrieve_obj(<T as response::NamedResponse>::name().into_owned())
     }
 }
+
+fn header_f64(resp: &HttpResponse, name: &str) -> Option<f64> {
+    resp.header(name).and_then(|v| v.parse::<f64>().ok())
+}
@@ -1,8 +1,14 @@
-pub use self::builder::{DnsRecType, DnsRecord, Droplet, RequestBuilder};
+pub use self::builder::{AlertPolicy, BandwidthDirection, BandwidthInterface, CdnEndpoint,
+                        CdnEndpointUpdate, Certificate, ConnectionPool, Database,
+                        DatabaseReplica, DnsRecType, DnsRecord, Droplet, DropletMetric, Firewall,
+                        KubernetesCluster, LoadBalancer, LogType, NodePool, Project,
+                        RequestBuilder, TagResource, UptimeAlert, UptimeCheck, Volume, Vpc};
 
 pub use self::dorequest::{BaseRequest, DoRequest};
-pub use self::page::PagedRequest;
+pub use self::page::{ItemIter, PageIter, PagedRequest};
+pub use self::wait::Wait;
 
 mod builder;
 mod page;
 mod dorequest;
+mod wait;
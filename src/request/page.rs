@@ -1,6 +1,137 @@
+use std::vec;
+
 use response::RawPagedResponse;
+use request::BaseRequest;
+use Error;
 
-pub trait PagedRequest {
+/// A request for one of DigitalOcean's paginated list endpoints (i.e. `/v2/droplets`)
+pub trait PagedRequest: BaseRequest {
     type Item;
-    fn retrieve_single_page(&self, url: String) -> Result<RawPagedResponse<Self::Item>, String>;
+
+    fn retrieve_single_page(&self, url: String) -> Result<RawPagedResponse<Self::Item>, Error>;
+
+    /// Retrieves the first page, which is the request itself. Following pages are always
+    /// requested with `GET`, but the first may be i.e. a `POST` returning a list of actions.
+    fn retrieve_first_page(&self) -> Result<RawPagedResponse<Self::Item>, Error> {
+        self.retrieve_single_page(self.url().to_owned())
+    }
+
+    /// Returns an iterator which lazily requests one page at a time, following the
+    /// `links.pages.next` URL of the previous page. Iteration stops after the last page, or
+    /// after the first page which fails (the error is yielded).
+    fn pages(&self) -> PageIter<Self>
+        where Self: Sized
+    {
+        PageIter {
+            req: self,
+            first: true,
+            next: None,
+        }
+    }
+
+    /// Returns an iterator over the items of every page, only requesting the next page once the
+    /// items of the current one have been consumed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::request::PagedRequest;
+    /// # let domgr = DoManager::with_token("asfasdfasdf");
+    /// let records = domgr.domain("super.com").dns_records().per_page(200);
+    /// let mut iter = records.iter().max_items(1000);
+    /// while let Some(rec) = iter.next() {
+    ///     match rec {
+    ///         Ok(rec) => println!("Record: {}", rec),
+    ///         Err(e) => { println!("Error: {}", e); break; }
+    ///     }
+    /// }
+    /// println!("Total records: {:?}", iter.total());
+    /// ```
+    fn iter(&self) -> ItemIter<Self>
+        where Self: Sized
+    {
+        ItemIter {
+            pages: self.pages(),
+            items: vec![].into_iter(),
+            total: None,
+            remaining: None,
+        }
+    }
+}
+
+/// A lazy iterator over the pages of a `PagedRequest`, see `PagedRequest::pages`
+pub struct PageIter<'r, R: PagedRequest + 'r> {
+    req: &'r R,
+    first: bool,
+    next: Option<String>,
+}
+
+impl<'r, R: PagedRequest + 'r> Iterator for PageIter<'r, R> {
+    type Item = Result<RawPagedResponse<R::Item>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let page = if self.first {
+            self.first = false;
+            self.req.retrieve_first_page()
+        } else {
+            match self.next.take() {
+                Some(url) => {
+                    debug!("Requesting page: {}", &url[..]);
+                    self.req.retrieve_single_page(url)
+                }
+                None => return None,
+            }
+        };
+        if let Ok(ref page) = page {
+            self.next = page.links.pages.as_ref().and_then(|p| p.next.clone());
+        }
+        Some(page)
+    }
+}
+
+/// A lazy iterator over the items of a `PagedRequest`, see `PagedRequest::iter`
+pub struct ItemIter<'r, R: PagedRequest + 'r> {
+    pages: PageIter<'r, R>,
+    items: vec::IntoIter<R::Item>,
+    total: Option<f64>,
+    remaining: Option<usize>,
+}
+
+impl<'r, R: PagedRequest + 'r> ItemIter<'r, R> {
+    /// Stops iterating after `max` items, without requesting any further pages
+    pub fn max_items(mut self, max: usize) -> ItemIter<'r, R> {
+        self.remaining = Some(max);
+        self
+    }
+
+    /// The total number of items reported by DigitalOcean (`meta.total`), which is known once
+    /// the first page has been retrieved
+    pub fn total(&self) -> Option<f64> { self.total }
+}
+
+impl<'r, R: PagedRequest + 'r> Iterator for ItemIter<'r, R> {
+    type Item = Result<R::Item, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        loop {
+            if let Some(item) = self.items.next() {
+                if let Some(ref mut n) = self.remaining {
+                    *n -= 1;
+                }
+                return Some(Ok(item));
+            }
+            match self.pages.next() {
+                Some(Ok(page)) => {
+                    self.total = Some(page.meta.total);
+                    self.items = page.collection.into_iter();
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => return None,
+            }
+        }
+    }
 }
@@ -0,0 +1,212 @@
+use std::cmp;
+use std::fmt;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use response::{Action, Deployment};
+use Error;
+
+/// Controls how an `Action` is polled while waiting for it to finish, see
+/// `RequestBuilder<Action>::retrieve_and_wait_with` and `Action::wait_with`. App deployments are
+/// polled the same way using a `Wait<Deployment>` made with `Wait::deployment`, see
+/// `RequestBuilder<Deployment>::retrieve_and_wait_with`.
+///
+/// By default the action is polled every 2 seconds, backing off by a factor of `1.5` up to once
+/// every 30 seconds, and waiting is given up after 10 minutes.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::time::Duration;
+/// # use doapi::DoManager;
+/// # use doapi::request::Wait;
+/// # let domgr = DoManager::with_token("asfasdfasdf");
+/// let wait = Wait::new()
+///                .interval(Duration::from_secs(5))
+///                .backoff(1.0)
+///                .timeout(Duration::from_secs(300))
+///                .on_progress(|a| println!("Action {:.0} is {}", a.id, a.status));
+/// match domgr.droplet("1234")
+///            .snapshot("before-upgrade")
+///            .retrieve_and_wait_with(wait) {
+///     Ok(action) => println!("Snapshot finished: {}", action),
+///     Err(e)     => println!("Error: {}", e)
+/// }
+/// ```
+pub struct Wait<'a, T = Action> {
+    interval: Duration,
+    max_interval: Duration,
+    backoff: f64,
+    timeout: Option<Duration>,
+    progress: Option<Box<FnMut(&T) + 'a>>,
+}
+
+impl<'a> Wait<'a, Action> {
+    /// Creates a new `Wait` for an action with the default settings
+    pub fn new() -> Wait<'a, Action> { Wait::default() }
+
+    /// Calls `poll` until the action it returns is no longer in progress, starting from
+    /// `action`
+    #[doc(hidden)]
+    pub fn run<F>(self, action: Action, poll: F) -> Result<Action, Error>
+        where F: FnMut() -> Result<Action, Error>
+    {
+        self.poll(action, poll)
+    }
+}
+
+impl<'a> Wait<'a, Deployment> {
+    /// Creates a new `Wait` for an app deployment with the default settings
+    pub fn deployment() -> Wait<'a, Deployment> { Wait::default() }
+
+    /// Calls `poll` until the deployment it returns has either gone live or failed, starting
+    /// from `deployment`
+    #[doc(hidden)]
+    pub fn run_deployment<F>(self, deployment: Deployment, poll: F) -> Result<Deployment, Error>
+        where F: FnMut() -> Result<Deployment, Error>
+    {
+        self.poll(deployment, poll)
+    }
+}
+
+impl<'a, T> Wait<'a, T> {
+    /// Sets the time to wait before polling the first time
+    pub fn interval(mut self, interval: Duration) -> Wait<'a, T> {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the longest the time between two polls may grow to through backoff
+    pub fn max_interval(mut self, max: Duration) -> Wait<'a, T> {
+        self.max_interval = max;
+        self
+    }
+
+    /// Sets the factor the time between two polls is multiplied by after each poll. Use `1.0` to
+    /// poll at a constant interval.
+    pub fn backoff(mut self, factor: f64) -> Wait<'a, T> {
+        self.backoff = if factor < 1.0 {
+            1.0
+        } else {
+            factor
+        };
+        self
+    }
+
+    /// Sets the total time to wait before giving up with `Error::ActionTimeout` (or
+    /// `Error::DeploymentTimeout`)
+    pub fn timeout(mut self, timeout: Duration) -> Wait<'a, T> {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Waits for as long as it takes
+    pub fn no_timeout(mut self) -> Wait<'a, T> {
+        self.timeout = None;
+        self
+    }
+
+    /// Sets a callback which is handed the latest state of the action (or deployment) after
+    /// each poll
+    pub fn on_progress<F>(mut self, f: F) -> Wait<'a, T>
+        where F: FnMut(&T) + 'a
+    {
+        self.progress = Some(Box::new(f));
+        self
+    }
+
+    fn poll<F>(mut self, first: T, mut poll: F) -> Result<T, Error>
+        where T: Settle,
+              F: FnMut() -> Result<T, Error>
+    {
+        let start = Instant::now();
+        let mut interval = self.interval;
+        let mut current = first;
+        loop {
+            debug!("{}", current.describe());
+            if let Some(ref mut f) = self.progress {
+                f(&current);
+            }
+            match current.settled() {
+                Some(true) => return Ok(current),
+                Some(false) => return Err(current.failed()),
+                None => {}
+            }
+            if let Some(timeout) = self.timeout {
+                if start.elapsed() + interval > timeout {
+                    return Err(current.timed_out());
+                }
+            }
+            thread::sleep(interval);
+            current = try!(poll());
+            interval = cmp::min(scale(interval, self.backoff), self.max_interval);
+        }
+    }
+}
+
+/// Something `Wait` can poll until it has settled
+trait Settle: Sized {
+    /// `None` while still in progress, otherwise whether it succeeded
+    fn settled(&self) -> Option<bool>;
+    fn describe(&self) -> String;
+    fn failed(self) -> Error;
+    fn timed_out(self) -> Error;
+}
+
+impl Settle for Action {
+    fn settled(&self) -> Option<bool> {
+        if self.is_completed() {
+            Some(true)
+        } else if self.is_errored() {
+            Some(false)
+        } else {
+            None
+        }
+    }
+    fn describe(&self) -> String { format!("Action {:.0} is {}", self.id, &self.status[..]) }
+    fn failed(self) -> Error { Error::ActionErrored(self) }
+    fn timed_out(self) -> Error { Error::ActionTimeout(self) }
+}
+
+impl Settle for Deployment {
+    fn settled(&self) -> Option<bool> {
+        if self.is_active() {
+            Some(true)
+        } else if self.is_failed() {
+            Some(false)
+        } else {
+            None
+        }
+    }
+    fn describe(&self) -> String { format!("Deployment {} is {}", self.id, &self.phase[..]) }
+    fn failed(self) -> Error { Error::DeploymentFailed(self) }
+    fn timed_out(self) -> Error { Error::DeploymentTimeout(self) }
+}
+
+impl<'a, T> Default for Wait<'a, T> {
+    fn default() -> Wait<'a, T> {
+        Wait {
+            interval: Duration::from_secs(2),
+            max_interval: Duration::from_secs(30),
+            backoff: 1.5,
+            timeout: Some(Duration::from_secs(600)),
+            progress: None,
+        }
+    }
+}
+
+impl<'a, T> fmt::Debug for Wait<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Wait {{ interval: {:?}, max_interval: {:?}, backoff: {}, timeout: {:?} }}",
+               self.interval,
+               self.max_interval,
+               self.backoff,
+               self.timeout)
+    }
+}
+
+fn scale(d: Duration, factor: f64) -> Duration {
+    let ms = d.as_secs() * 1000 + (d.subsec_nanos() / 1_000_000) as u64;
+    Duration::from_millis((ms as f64 * factor) as u64)
+}
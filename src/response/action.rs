@@ -24,23 +24,63 @@ use std::borrow::Cow;
 use response::region::Region;
 use response::NamedResponse;
 use response;
+use request::{DoRequest, Wait};
+use {DoManager, Error};
 
 #[derive(Deserialize, Debug)]
 pub struct Action {
-    id: f64,
-    status: String,
+    pub id: f64,
+    pub status: String,
     #[serde(rename="type")]
-    action_type: String,
-    started_at: String,
-    completed_at: Option<String>,
-    resource_id: f64,
-    resource_type: String,
-    region: Region,
-    region_slug: Option<String>,
+    pub action_type: String,
+    pub started_at: String,
+    pub completed_at: Option<String>,
+    pub resource_id: f64,
+    pub resource_type: String,
+    pub region: Region,
+    pub region_slug: Option<String>,
 }
 
 impl response::NotArray for Action {}
 
+impl Action {
+    /// Returns `true` if the action is still running
+    pub fn is_in_progress(&self) -> bool { self.status == "in-progress" }
+
+    /// Returns `true` if the action finished successfully
+    pub fn is_completed(&self) -> bool { self.status == "completed" }
+
+    /// Returns `true` if the action finished unsuccessfully
+    pub fn is_errored(&self) -> bool { self.status == "errored" }
+
+    /// Blocks until the action has either completed or errored, polling with the default `Wait`
+    /// settings. If the action ends in `errored` an `Error::ActionErrored` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::DoManager;
+    /// # use doapi::DoRequest;
+    /// # let domgr = DoManager::with_token("asfasdfasdf");
+    /// let action = domgr.droplet("1234").reboot().retrieve().unwrap();
+    /// // ... do some other work
+    /// match action.wait(&domgr) {
+    ///     Ok(action) => println!("Rebooted at {}", action.completed_at.unwrap()),
+    ///     Err(e)     => println!("Error: {}", e)
+    /// }
+    /// ```
+    pub fn wait(&self, domgr: &DoManager) -> Result<Action, Error> {
+        self.wait_with(domgr, Wait::new())
+    }
+
+    /// Like `Action::wait`, but polls according to `wait`
+    pub fn wait_with(&self, domgr: &DoManager, wait: Wait) -> Result<Action, Error> {
+        let rb = domgr.account().action(&format!("{:.0}", self.id)[..]);
+        let first = try!(rb.retrieve());
+        wait.run(first, || rb.retrieve())
+    }
+}
+
 impl fmt::Display for Action {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f,
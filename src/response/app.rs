@@ -0,0 +1,536 @@
+// id               string      A unique ID that can be used to identify and reference an app.
+// owner_uuid       string      The ID of the account the app belongs to.
+// spec             object      The app specification (see `AppSpec`).
+// default_ingress  string      The default URL of the app, once it has been deployed.
+// live_url         string      The URL the app is live at.
+// active_deployment object     The deployment currently serving the app.
+// in_progress_deployment object The deployment currently being built or deployed.
+// created_at       string      A time value given in ISO8601 combined date and time format that
+// represents when the app was created.
+// updated_at       string      A time value given in ISO8601 combined date and time format that
+// represents when the app was last updated.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use response::NamedResponse;
+use response;
+
+// repo             string      The name of the repo in the format "owner/repo".
+// branch           string      The name of the branch to use.
+// deploy_on_push   boolean     Whether to automatically deploy new commits made to the branch.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GitHubSource {
+    pub repo: String,
+    pub branch: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub deploy_on_push: Option<bool>,
+}
+
+impl GitHubSource {
+    pub fn new(repo: &str, branch: &str) -> GitHubSource {
+        GitHubSource {
+            repo: repo.to_owned(),
+            branch: branch.to_owned(),
+            deploy_on_push: None,
+        }
+    }
+}
+
+// repo_clone_url   string      The clone URL of the repo.
+// branch           string      The name of the branch to use.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GitSource {
+    pub repo_clone_url: String,
+    pub branch: String,
+}
+
+// registry_type    string      "DOCR" or "DOCKER_HUB".
+// registry         string      The registry name, required for "DOCKER_HUB".
+// repository       string      The repository name.
+// tag              string      The repository tag, defaults to "latest".
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ImageSource {
+    pub registry_type: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub registry: Option<String>,
+    pub repository: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tag: Option<String>,
+}
+
+// key              string      The name of the variable.
+// value            string      The value of the variable, encrypted once stored if the type is
+// "SECRET".
+// scope            string      "RUN_TIME", "BUILD_TIME" or "RUN_AND_BUILD_TIME" (the default).
+// type             string      "GENERAL" (the default) or "SECRET".
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AppEnv {
+    pub key: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(rename="type")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub env_type: Option<String>,
+}
+
+impl AppEnv {
+    /// A plain variable available at build and run time
+    pub fn new(key: &str, value: &str) -> AppEnv {
+        AppEnv {
+            key: key.to_owned(),
+            value: Some(value.to_owned()),
+            scope: None,
+            env_type: None,
+        }
+    }
+
+    /// A variable which is encrypted once stored, available at build and run time
+    pub fn secret(key: &str, value: &str) -> AppEnv {
+        AppEnv { env_type: Some("SECRET".to_owned()), ..AppEnv::new(key, value) }
+    }
+}
+
+// path             string      The path prefix routed to the component.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AppRoute {
+    pub path: String,
+}
+
+// name (true)      string      The name of the component, unique within the app.
+// github, git or image object  Where the code or image of the component comes from.
+// source_dir       string      The directory within the repo to build from.
+// dockerfile_path  string      The path to a Dockerfile, which is used instead of a buildpack.
+// build_command    string      The command used to build the component.
+// run_command      string      The command used to run the component.
+// environment_slug string      The buildpack stack to use (i.e. "node-js").
+// envs             array       The environment variables of the component.
+// instance_size_slug string    The size of the containers running the component.
+// instance_count   number      The number of containers running the component.
+// http_port        number      The port the service listens on.
+// routes           array       The paths routed to the service.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AppService {
+    pub name: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub github: Option<GitHubSource>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub git: Option<GitSource>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub image: Option<ImageSource>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub source_dir: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub dockerfile_path: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub build_command: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub run_command: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub environment_slug: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub envs: Option<Vec<AppEnv>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub instance_size_slug: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub instance_count: Option<u64>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub http_port: Option<u64>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub routes: Option<Vec<AppRoute>>,
+}
+
+impl AppService {
+    /// A service built from `github`, with default settings for everything else
+    pub fn new(name: &str, github: GitHubSource) -> AppService {
+        AppService {
+            name: name.to_owned(),
+            github: Some(github),
+            git: None,
+            image: None,
+            source_dir: None,
+            dockerfile_path: None,
+            build_command: None,
+            run_command: None,
+            environment_slug: None,
+            envs: None,
+            instance_size_slug: None,
+            instance_count: None,
+            http_port: None,
+            routes: None,
+        }
+    }
+}
+
+// Workers take the same fields as services, less `http_port` and `routes`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AppWorker {
+    pub name: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub github: Option<GitHubSource>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub git: Option<GitSource>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub image: Option<ImageSource>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub source_dir: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub dockerfile_path: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub build_command: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub run_command: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub environment_slug: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub envs: Option<Vec<AppEnv>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub instance_size_slug: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub instance_count: Option<u64>,
+}
+
+impl AppWorker {
+    /// A worker built from `github`, with default settings for everything else
+    pub fn new(name: &str, github: GitHubSource) -> AppWorker {
+        AppWorker {
+            name: name.to_owned(),
+            github: Some(github),
+            git: None,
+            image: None,
+            source_dir: None,
+            dockerfile_path: None,
+            build_command: None,
+            run_command: None,
+            environment_slug: None,
+            envs: None,
+            instance_size_slug: None,
+            instance_count: None,
+        }
+    }
+}
+
+// Jobs take the same fields as workers, plus:
+// kind             string      When the job runs: "PRE_DEPLOY", "POST_DEPLOY" or
+// "FAILED_DEPLOY".
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AppJob {
+    pub name: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub github: Option<GitHubSource>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub git: Option<GitSource>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub image: Option<ImageSource>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub source_dir: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub dockerfile_path: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub build_command: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub run_command: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub environment_slug: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub envs: Option<Vec<AppEnv>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub instance_size_slug: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub instance_count: Option<u64>,
+}
+
+impl AppJob {
+    /// A job built from `github` which runs at `kind` (i.e. "PRE_DEPLOY")
+    pub fn new(name: &str, kind: &str, github: GitHubSource) -> AppJob {
+        AppJob {
+            name: name.to_owned(),
+            kind: Some(kind.to_owned()),
+            github: Some(github),
+            git: None,
+            image: None,
+            source_dir: None,
+            dockerfile_path: None,
+            build_command: None,
+            run_command: None,
+            environment_slug: None,
+            envs: None,
+            instance_size_slug: None,
+            instance_count: None,
+        }
+    }
+}
+
+// name (true)      string      The name of the component, unique within the app.
+// github or git    object      Where the code of the site comes from.
+// source_dir       string      The directory within the repo to build from.
+// build_command    string      The command used to build the site.
+// environment_slug string      The buildpack stack to use (i.e. "hugo").
+// envs             array       The environment variables used while building the site.
+// output_dir       string      The directory the build writes the site to.
+// index_document   string      The name of the index document, defaults to "index.html".
+// error_document   string      The name of the error document, defaults to "404.html".
+// catchall_document string     The document served for any path which isn't found.
+// routes           array       The paths routed to the site.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AppStaticSite {
+    pub name: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub github: Option<GitHubSource>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub git: Option<GitSource>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub source_dir: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub build_command: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub environment_slug: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub envs: Option<Vec<AppEnv>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub output_dir: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub index_document: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub error_document: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub catchall_document: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub routes: Option<Vec<AppRoute>>,
+}
+
+impl AppStaticSite {
+    /// A static site built from `github`, with default settings for everything else
+    pub fn new(name: &str, github: GitHubSource) -> AppStaticSite {
+        AppStaticSite {
+            name: name.to_owned(),
+            github: Some(github),
+            git: None,
+            source_dir: None,
+            build_command: None,
+            environment_slug: None,
+            envs: None,
+            output_dir: None,
+            index_document: None,
+            error_document: None,
+            catchall_document: None,
+            routes: None,
+        }
+    }
+}
+
+// name (true)      string      The name of the app, unique within the account.
+// region           string      The slug of the region to deploy the app to (i.e. "nyc").
+// services         array       Components serving HTTP requests.
+// workers          array       Components running in the background.
+// jobs             array       Components running before or after a deployment.
+// static_sites     array       Components serving static files.
+// envs             array       Environment variables shared by every component.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AppSpec {
+    pub name: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub services: Option<Vec<AppService>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub workers: Option<Vec<AppWorker>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub jobs: Option<Vec<AppJob>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub static_sites: Option<Vec<AppStaticSite>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub envs: Option<Vec<AppEnv>>,
+}
+
+impl AppSpec {
+    /// A spec without any components
+    pub fn new(name: &str) -> AppSpec {
+        AppSpec {
+            name: name.to_owned(),
+            region: None,
+            services: None,
+            workers: None,
+            jobs: None,
+            static_sites: None,
+            envs: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct App {
+    pub id: String,
+    pub owner_uuid: Option<String>,
+    pub spec: AppSpec,
+    pub default_ingress: Option<String>,
+    pub live_url: Option<String>,
+    pub active_deployment: Option<Deployment>,
+    pub in_progress_deployment: Option<Deployment>,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+}
+
+impl response::NotArray for App {}
+
+impl fmt::Display for App {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "ID: {}\n\
+                Name: {}\n\
+                Live URL: {}\n\
+                Active Deployment: {}\n\
+                In Progress Deployment: {}\n\
+                Created At: {}\n",
+               self.id,
+               self.spec.name,
+               self.live_url.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.active_deployment.as_ref().map(|d| &d.id[..]).unwrap_or("None"),
+               self.in_progress_deployment.as_ref().map(|d| &d.id[..]).unwrap_or("None"),
+               self.created_at)
+    }
+}
+
+pub type Apps = Vec<App>;
+
+impl NamedResponse for App {
+    fn name<'a>() -> Cow<'a, str> { "app".into() }
+}
+
+// success_steps    number      The number of steps which finished successfully.
+// error_steps      number      The number of steps which failed.
+// pending_steps    number      The number of steps which haven't started yet.
+// running_steps    number      The number of steps which are running.
+// total_steps      number      The total number of steps.
+#[derive(Deserialize, Debug)]
+pub struct DeploymentProgress {
+    pub success_steps: Option<f64>,
+    pub error_steps: Option<f64>,
+    pub pending_steps: Option<f64>,
+    pub running_steps: Option<f64>,
+    pub total_steps: Option<f64>,
+}
+
+// id               string      A unique ID that can be used to identify and reference a
+// deployment.
+// spec             object      The app specification the deployment was made from.
+// phase            string      "UNKNOWN", "PENDING_BUILD", "BUILDING", "PENDING_DEPLOY",
+// "DEPLOYING", "ACTIVE", "SUPERSEDED", "ERROR" or "CANCELED".
+// progress         object      The number of steps of the deployment in each state.
+// cause            string      What triggered the deployment (i.e. "manual").
+// created_at       string      A time value given in ISO8601 combined date and time format that
+// represents when the deployment was created.
+// updated_at       string      A time value given in ISO8601 combined date and time format that
+// represents when the deployment was last updated.
+#[derive(Deserialize, Debug)]
+pub struct Deployment {
+    pub id: String,
+    pub spec: Option<AppSpec>,
+    pub phase: String,
+    pub progress: Option<DeploymentProgress>,
+    pub cause: Option<String>,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+}
+
+impl Deployment {
+    /// Returns `true` if the deployment went live (even if it has since been superseded)
+    pub fn is_active(&self) -> bool { self.phase == "ACTIVE" || self.phase == "SUPERSEDED" }
+
+    /// Returns `true` if the deployment errored or was canceled
+    pub fn is_failed(&self) -> bool { self.phase == "ERROR" || self.phase == "CANCELED" }
+
+    /// Returns `true` if the deployment was canceled
+    pub fn is_canceled(&self) -> bool { self.phase == "CANCELED" }
+}
+
+impl response::NotArray for Deployment {}
+
+impl fmt::Display for Deployment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let steps = |s: Option<f64>| s.map(|n| format!("{:.0}", n)).unwrap_or("?".to_owned());
+        write!(f,
+               "ID: {}\n\
+                Phase: {}\n\
+                Progress: {}/{} steps\n\
+                Cause: {}\n\
+                Created At: {}\n",
+               self.id,
+               self.phase,
+               steps(self.progress.as_ref().and_then(|p| p.success_steps)),
+               steps(self.progress.as_ref().and_then(|p| p.total_steps)),
+               self.cause.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.created_at)
+    }
+}
+
+pub type Deployments = Vec<Deployment>;
+
+impl NamedResponse for Deployment {
+    fn name<'a>() -> Cow<'a, str> { "deployment".into() }
+}
+
+// app_name_available boolean   Whether the name in the spec is still free.
+// app_is_static    boolean     Whether the app only consists of static sites.
+// app_cost         number      The monthly cost of the app in USD.
+// spec             object      The spec, with any defaults filled in.
+#[derive(Deserialize, Debug)]
+pub struct AppProposal {
+    pub app_name_available: Option<bool>,
+    pub app_is_static: Option<bool>,
+    pub app_cost: Option<f64>,
+    pub spec: AppSpec,
+}
+
+impl response::NotArray for AppProposal {}
+
+impl fmt::Display for AppProposal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Name: {}\n\
+                Name Available: {}\n\
+                Static: {}\n\
+                Monthly Cost: {}\n",
+               self.spec.name,
+               self.app_name_available.map(|b| b.to_string()).unwrap_or("?".to_owned()),
+               self.app_is_static.map(|b| b.to_string()).unwrap_or("?".to_owned()),
+               self.app_cost.map(|c| format!("${:.2}", c)).unwrap_or("?".to_owned()))
+    }
+}
+
+impl NamedResponse for AppProposal {
+    // The proposal isn't wrapped in an object, see `request::builder::apps`
+    fn name<'a>() -> Cow<'a, str> { "proposal".into() }
+}
+
+// live_url         string      A URL streaming the logs as they are written.
+// historic_urls    array       URLs of the logs written so far.
+#[derive(Deserialize, Debug)]
+pub struct AppLogs {
+    pub live_url: Option<String>,
+    pub historic_urls: Option<Vec<String>>,
+}
+
+impl response::NotArray for AppLogs {}
+
+impl fmt::Display for AppLogs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Live URL: {}\n\
+                Historic URLs:{}\n",
+               self.live_url.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.historic_urls
+                   .as_ref()
+                   .map(|v| v.iter().fold(String::new(), |acc, s| acc + &format!(" {},", s)[..]))
+                   .unwrap_or(String::new()))
+    }
+}
+
+impl NamedResponse for AppLogs {
+    // The URLs aren't wrapped in an object, see `request::builder::apps`
+    fn name<'a>() -> Cow<'a, str> { "logs".into() }
+}
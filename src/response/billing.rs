@@ -0,0 +1,288 @@
+// Amounts are given by DigitalOcean as decimal strings in USD (i.e. "12.34"), and are left as
+// such so that no precision is lost.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use response::NamedResponse;
+use response;
+
+// month_to_date_balance string Balance as of the `generated_at` time.
+// account_balance  string      Current balance of the latest billing activity.
+// month_to_date_usage string   Amount used in the current billing period as of the
+// `generated_at` time.
+// generated_at     string      A time value given in ISO8601 combined date and time format that
+// represents when the balance was generated.
+#[derive(Deserialize, Debug)]
+pub struct Balance {
+    pub month_to_date_balance: String,
+    pub account_balance: String,
+    pub month_to_date_usage: String,
+    pub generated_at: String,
+}
+
+impl response::NotArray for Balance {}
+
+impl fmt::Display for Balance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Month To Date Balance: {}\n\
+                Account Balance: {}\n\
+                Month To Date Usage: {}\n\
+                Generated At: {}\n",
+               self.month_to_date_balance,
+               self.account_balance,
+               self.month_to_date_usage,
+               self.generated_at)
+    }
+}
+
+impl NamedResponse for Balance {
+    // The balance isn't wrapped in an object, see `request::builder::billing`
+    fn name<'a>() -> Cow<'a, str> { "balance".into() }
+}
+
+// description      string      Description of the billing history entry.
+// amount           string      Amount of the entry (negative for payments and credits).
+// invoice_id       string      ID of the invoice, if the entry is an invoice.
+// invoice_uuid     string      UUID of the invoice, if the entry is an invoice.
+// date             string      A time value given in ISO8601 combined date and time format of
+// the entry.
+// type             string      "Invoice", "Payment", "Refund", "Credit", "Adjustment" or
+// "Charge".
+#[derive(Deserialize, Debug)]
+pub struct BillingHistoryEntry {
+    pub description: String,
+    pub amount: String,
+    pub invoice_id: Option<String>,
+    pub invoice_uuid: Option<String>,
+    pub date: String,
+    #[serde(rename="type")]
+    pub entry_type: String,
+}
+
+impl response::NotArray for BillingHistoryEntry {}
+
+impl fmt::Display for BillingHistoryEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Date: {}\n\
+                Type: {}\n\
+                Description: {}\n\
+                Amount: {}\n\
+                Invoice UUID: {}\n",
+               self.date,
+               self.entry_type,
+               self.description,
+               self.amount,
+               self.invoice_uuid.as_ref().map(|s| &s[..]).unwrap_or("None"))
+    }
+}
+
+pub type BillingHistory = Vec<BillingHistoryEntry>;
+
+impl NamedResponse for BillingHistoryEntry {
+    fn name<'a>() -> Cow<'a, str> { "billing_history_entry".into() }
+
+    fn collection_name<'a>() -> Cow<'a, str> { "billing_history".into() }
+}
+
+// invoice_uuid     string      The UUID of the invoice.
+// amount           string      Total amount of the invoice.
+// invoice_period   string      Billing period of the invoice (i.e. "2020-01").
+// updated_at       string      A time value given in ISO8601 combined date and time format that
+// represents when the invoice was last updated.
+#[derive(Deserialize, Debug)]
+pub struct Invoice {
+    pub invoice_uuid: String,
+    pub amount: String,
+    pub invoice_period: String,
+    pub updated_at: Option<String>,
+}
+
+impl response::NotArray for Invoice {}
+
+impl fmt::Display for Invoice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "UUID: {}\n\
+                Period: {}\n\
+                Amount: {}\n",
+               self.invoice_uuid,
+               self.invoice_period,
+               self.amount)
+    }
+}
+
+pub type Invoices = Vec<Invoice>;
+
+impl NamedResponse for Invoice {
+    fn name<'a>() -> Cow<'a, str> { "invoice".into() }
+}
+
+// product          string      Name of the product being billed (i.e. "Droplets").
+// resource_uuid    string      UUID of the resource being billed.
+// group_description string     Description of the invoice item, when part of a group.
+// description      string      Description of the invoice item.
+// amount           string      Billed amount of the item.
+// duration         string      Duration of time the item was used.
+// duration_unit    string      Unit of `duration` (i.e. "Hours").
+// start_time       string      When the use of the item started.
+// end_time         string      When the use of the item ended.
+// project_name     string      Name of the project the resource belonged to.
+// category         string      Category of the item (i.e. "iaas").
+#[derive(Deserialize, Debug)]
+pub struct InvoiceItem {
+    pub product: String,
+    pub resource_uuid: Option<String>,
+    pub group_description: Option<String>,
+    pub description: String,
+    pub amount: String,
+    pub duration: Option<String>,
+    pub duration_unit: Option<String>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub project_name: Option<String>,
+    pub category: Option<String>,
+}
+
+impl response::NotArray for InvoiceItem {}
+
+impl fmt::Display for InvoiceItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Product: {}\n\
+                Description: {}\n\
+                Amount: {}\n\
+                Duration: {} {}\n\
+                Project: {}\n",
+               self.product,
+               self.description,
+               self.amount,
+               self.duration.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.duration_unit.as_ref().map(|s| &s[..]).unwrap_or(""),
+               self.project_name.as_ref().map(|s| &s[..]).unwrap_or("None"))
+    }
+}
+
+pub type InvoiceItems = Vec<InvoiceItem>;
+
+impl NamedResponse for InvoiceItem {
+    fn name<'a>() -> Cow<'a, str> { "invoice_item".into() }
+}
+
+// name             string      Name of the charge (i.e. "Droplets").
+// amount           string      Total amount of the charge.
+// count            string      Number of items making up the charge.
+#[derive(Deserialize, Debug)]
+pub struct ProductCharge {
+    pub name: String,
+    pub amount: String,
+    pub count: Option<String>,
+}
+
+// name             string      Name of the category (i.e. "Product usage charges").
+// amount           string      Total amount of the category.
+// count            string      Number of items in the category.
+// items            array       The totals per product.
+#[derive(Deserialize, Debug)]
+pub struct ProductCharges {
+    pub name: String,
+    pub amount: String,
+    pub count: Option<String>,
+    pub items: Vec<ProductCharge>,
+}
+
+// name             string      Name of the category (i.e. "Taxes").
+// amount           string      Total amount of the category.
+#[derive(Deserialize, Debug)]
+pub struct SummaryAmount {
+    pub name: String,
+    pub amount: String,
+}
+
+// invoice_uuid     string      The UUID of the invoice.
+// billing_period   string      Billing period of the invoice (i.e. "2020-01").
+// amount           string      Total amount of the invoice.
+// user_name        string      Name of the account holder.
+// user_company     string      Company of the account holder.
+// user_email       string      Email of the account holder.
+// product_charges  object      Usage charges, with the totals per product.
+// overages         object      Charges for use beyond included allowances.
+// taxes            object      Taxes applied.
+// credits_and_adjustments object Credits and adjustments applied.
+#[derive(Deserialize, Debug)]
+pub struct InvoiceSummary {
+    pub invoice_uuid: String,
+    pub billing_period: String,
+    pub amount: String,
+    pub user_name: Option<String>,
+    pub user_company: Option<String>,
+    pub user_email: Option<String>,
+    pub product_charges: ProductCharges,
+    pub overages: SummaryAmount,
+    pub taxes: SummaryAmount,
+    pub credits_and_adjustments: SummaryAmount,
+}
+
+impl response::NotArray for InvoiceSummary {}
+
+impl fmt::Display for InvoiceSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "UUID: {}\n\
+                Period: {}\n\
+                Amount: {}\n\
+                {}: {}\n\
+                {}\
+                {}: {}\n\
+                {}: {}\n\
+                {}: {}\n",
+               self.invoice_uuid,
+               self.billing_period,
+               self.amount,
+               self.product_charges.name,
+               self.product_charges.amount,
+               self.product_charges
+                   .items
+                   .iter()
+                   .fold(String::new(),
+                         |acc, i| acc + &format!("    {}: {}\n", i.name, i.amount)[..]),
+               self.overages.name,
+               self.overages.amount,
+               self.taxes.name,
+               self.taxes.amount,
+               self.credits_and_adjustments.name,
+               self.credits_and_adjustments.amount)
+    }
+}
+
+impl NamedResponse for InvoiceSummary {
+    // The summary isn't wrapped in an object, see `request::builder::billing`
+    fn name<'a>() -> Cow<'a, str> { "summary".into() }
+}
+
+/// A downloaded invoice (CSV or PDF). The file is never parsed, `bytes` holds the body exactly as
+/// it was returned.
+#[derive(Deserialize, Debug)]
+pub struct InvoiceFile {
+    /// The `Content-Type` of the file (i.e. "text/csv" or "application/pdf")
+    pub content_type: Option<String>,
+    pub bytes: Vec<u8>,
+}
+
+impl response::NotArray for InvoiceFile {}
+
+impl fmt::Display for InvoiceFile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Content Type: {}\n\
+                Size: {} bytes\n",
+               self.content_type.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.bytes.len())
+    }
+}
+
+impl NamedResponse for InvoiceFile {
+    fn name<'a>() -> Cow<'a, str> { "file".into() }
+}
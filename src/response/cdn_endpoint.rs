@@ -0,0 +1,59 @@
+// id               string      A unique ID that can be used to identify and reference a CDN
+// endpoint.
+// origin           string      The fully qualified domain name (FQDN) for the origin server
+// which provides the content for the CDN. This is currently restricted to a Space.
+// endpoint         string      The fully qualified domain name (FQDN) from which the CDN-backed
+// content is served.
+// created_at       string      A time value given in ISO8601 combined date and time format that
+// represents when the CDN endpoint was created.
+// ttl              number      The amount of time the content is cached by the CDN's edge
+// servers in seconds (60, 600, 3600, 86400 or 604800).
+// certificate_id   string      The ID of a DigitalOcean managed TLS certificate used for SSL
+// when a custom subdomain is provided.
+// custom_domain    string      The fully qualified domain name (FQDN) of the custom subdomain
+// used with the CDN endpoint.
+
+use std::fmt;
+use std::borrow::Cow;
+
+use response::NamedResponse;
+use response;
+
+#[derive(Deserialize, Debug)]
+pub struct CdnEndpoint {
+    pub id: String,
+    pub origin: String,
+    pub endpoint: String,
+    pub created_at: String,
+    pub ttl: f64,
+    pub certificate_id: Option<String>,
+    pub custom_domain: Option<String>,
+}
+
+impl response::NotArray for CdnEndpoint {}
+
+impl fmt::Display for CdnEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "ID: {}\n\
+                Origin: {}\n\
+                Endpoint: {}\n\
+                TTL: {:.0}\n\
+                Certificate ID: {}\n\
+                Custom Domain: {}\n\
+                Created At: {}\n",
+               self.id,
+               self.origin,
+               self.endpoint,
+               self.ttl,
+               self.certificate_id.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.custom_domain.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.created_at)
+    }
+}
+
+pub type CdnEndpoints = Vec<CdnEndpoint>;
+
+impl NamedResponse for CdnEndpoint {
+    fn name<'a>() -> Cow<'a, str> { "endpoint".into() }
+}
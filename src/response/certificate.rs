@@ -0,0 +1,64 @@
+// id               string      A unique ID that can be used to identify and reference a
+// certificate.
+// name             string      A unique human-readable name referring to a certificate.
+// not_after        string      A time value given in ISO8601 combined date and time format that
+// represents the certificate's expiration date.
+// sha1_fingerprint string      A unique identifier generated from the SHA-1 fingerprint of the
+// certificate.
+// created_at       string      A time value given in ISO8601 combined date and time format that
+// represents when the certificate was created.
+// dns_names        array       An array of fully qualified domain names (FQDNs) for which the
+// certificate was issued.
+// state            string      A string representing the current state of the certificate
+// ("pending", "verified" or "error").
+// type             string      A string representing the type of the certificate ("custom" or
+// "lets_encrypt").
+
+use std::fmt;
+use std::borrow::Cow;
+
+use response::NamedResponse;
+use response;
+
+#[derive(Deserialize, Debug)]
+pub struct Certificate {
+    pub id: String,
+    pub name: String,
+    pub not_after: String,
+    pub sha1_fingerprint: String,
+    pub created_at: String,
+    pub dns_names: Vec<String>,
+    pub state: String,
+    #[serde(rename="type")]
+    pub cert_type: String,
+}
+
+impl response::NotArray for Certificate {}
+
+impl fmt::Display for Certificate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "ID: {}\n\
+                Name: {}\n\
+                Type: {}\n\
+                State: {}\n\
+                DNS Names:{}\n\
+                SHA-1 Fingerprint: {}\n\
+                Not After: {}\n\
+                Created At: {}\n",
+               self.id,
+               self.name,
+               self.cert_type,
+               self.state,
+               self.dns_names.iter().fold(String::new(), |acc, s| acc + &format!(" {},", s)[..]),
+               self.sha1_fingerprint,
+               self.not_after,
+               self.created_at)
+    }
+}
+
+pub type Certificates = Vec<Certificate>;
+
+impl NamedResponse for Certificate {
+    fn name<'a>() -> Cow<'a, str> { "certificate".into() }
+}
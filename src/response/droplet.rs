@@ -46,6 +46,8 @@
 // backups feature, if backups are configured for the Droplet. This object
 // contains keys for the start and end times of the window during which the
 // backup will start.
+// vpc_uuid             string              The unique identifier of the VPC to
+// which the Droplet is assigned.
 
 use std::fmt;
 use std::borrow::Cow;
@@ -73,6 +75,7 @@ pub struct Droplet {
     pub size: Size,
     pub size_slug: String,
     pub networks: Networks,
+    pub vpc_uuid: Option<String>,
 }
 
 impl response::NotArray for Droplet {}
@@ -97,7 +100,8 @@ impl fmt::Display for Droplet {
                   Size Slug: {}\n\
                   Network: \n\t{}\n\
                   Kernel: \n\t{}\n\
-                  Next Backup Window: {}\n",
+                  Next Backup Window: {}\n\
+                  VPC: {}\n",
                self.id,
                self.name,
                self.memory,
@@ -143,7 +147,8 @@ impl fmt::Display for Droplet {
                    format!("{}", &k.to_string()[..].replace("\n", "\n\t"))
                } else {
                    "None".to_owned()
-               })
+               },
+               self.vpc_uuid.as_ref().map(|s| &s[..]).unwrap_or("None"))
 
     }
 }
@@ -153,3 +158,7 @@ impl NamedResponse for Droplet {
 }
 
 pub type Droplets = Vec<Droplet>;
+
+/// Types the requests returned by `droplets().with_tag(..)`, which list the droplets with a tag
+/// and act on all of them at once
+pub enum TaggedDroplets {}
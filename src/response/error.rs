@@ -2,11 +2,12 @@ use std::fmt;
 
 // example: Missing action
 // {"id":"not_found","message":"The resource you were accessing could not be
-// found."}
-#[derive(Deserialize, Debug)]
+// found.","request_id":"4d9d8375-3c56-4925-a3e7-eb137fed17e9"}
+#[derive(Deserialize, Debug, Clone)]
 pub struct DoError {
-    id: String,
-    message: String,
+    pub id: String,
+    pub message: String,
+    pub request_id: Option<String>,
 }
 
 impl fmt::Display for DoError {
@@ -0,0 +1,210 @@
+// id               string      A unique ID that can be used to identify and reference a
+// firewall.
+// status           string      A status string indicating the current state of the firewall.
+// This can be "waiting", "succeeded", or "failed".
+// created_at       string      A time value given in ISO8601 combined date and time format
+// that represents when the firewall was created.
+// pending_changes  array       An array of objects each containing the fields "droplet_id",
+// "removing", and "status". It is provided to detail exactly which Droplets are having their
+// security policies updated.
+// name             string      A human-readable name for a firewall.
+// inbound_rules    array       The inbound access rule block for the firewall.
+// outbound_rules   array       The outbound access rule block for the firewall.
+// droplet_ids      array       An array containing the IDs of the Droplets assigned to the
+// firewall.
+// tags             array       An array containing the names of the tags assigned to the
+// firewall.
+
+use std::fmt;
+use std::borrow::Cow;
+
+use response::NamedResponse;
+use response;
+
+// addresses            array   An array of strings containing the IPv4 addresses, IPv6
+// addresses, IPv4 CIDRs, and/or IPv6 CIDRs.
+// droplet_ids          array   An array containing the IDs of the Droplets.
+// load_balancer_uids   array   An array containing the IDs of the Load Balancers.
+// tags                 array   An array containing the names of Tags corresponding to groups of
+// Droplets.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Targets {
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub addresses: Option<Vec<String>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub droplet_ids: Option<Vec<u64>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub load_balancer_uids: Option<Vec<String>>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+impl Targets {
+    /// Targets matching any of `addresses` (IPs or CIDRs, i.e. `"0.0.0.0/0"`)
+    pub fn addresses(addresses: &[&str]) -> Targets {
+        Targets {
+            addresses: Some(addresses.iter().map(|s| (*s).to_owned()).collect()),
+            ..Targets::default()
+        }
+    }
+
+    /// Targets matching the droplets with any of `ids`
+    pub fn droplets(ids: &[u64]) -> Targets {
+        Targets {
+            droplet_ids: Some(ids.to_vec()),
+            ..Targets::default()
+        }
+    }
+
+    /// Targets matching the load balancers with any of `uids`
+    pub fn load_balancers(uids: &[&str]) -> Targets {
+        Targets {
+            load_balancer_uids: Some(uids.iter().map(|s| (*s).to_owned()).collect()),
+            ..Targets::default()
+        }
+    }
+
+    /// Targets matching the droplets tagged with any of `tags`
+    pub fn tags(tags: &[&str]) -> Targets {
+        Targets {
+            tags: Some(tags.iter().map(|s| (*s).to_owned()).collect()),
+            ..Targets::default()
+        }
+    }
+}
+
+impl fmt::Display for Targets {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut all: Vec<String> = vec![];
+        if let Some(ref v) = self.addresses {
+            all.extend(v.iter().cloned());
+        }
+        if let Some(ref v) = self.droplet_ids {
+            all.extend(v.iter().map(|id| format!("droplet:{}", id)));
+        }
+        if let Some(ref v) = self.load_balancer_uids {
+            all.extend(v.iter().map(|id| format!("load_balancer:{}", id)));
+        }
+        if let Some(ref v) = self.tags {
+            all.extend(v.iter().map(|t| format!("tag:{}", t)));
+        }
+        write!(f, "{}", all.join(","))
+    }
+}
+
+// protocol     string  The type of traffic to be allowed ("tcp", "udp", or "icmp").
+// ports        string  The ports on which traffic will be allowed specified as a string
+// containing a single port, a range (e.g. "8000-9000"), or "0" when all ports are open for a
+// protocol. For ICMP rules this parameter will always return "0".
+// sources      object  An object specifying locations from which inbound traffic will be
+// accepted.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InboundRule {
+    pub protocol: String,
+    pub ports: String,
+    pub sources: Targets,
+}
+
+impl InboundRule {
+    /// Allows `protocol` traffic on `ports` (i.e. `"22"`, `"8000-9000"` or `"0"` for all) from
+    /// `sources`
+    pub fn new(protocol: &str, ports: &str, sources: Targets) -> InboundRule {
+        InboundRule {
+            protocol: protocol.to_owned(),
+            ports: ports.to_owned(),
+            sources: sources,
+        }
+    }
+}
+
+impl fmt::Display for InboundRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{} from {}", self.protocol, self.ports, self.sources)
+    }
+}
+
+// protocol     string  The type of traffic to be allowed ("tcp", "udp", or "icmp").
+// ports        string  The ports on which traffic will be allowed, see InboundRule.
+// destinations object  An object specifying locations to which outbound traffic that will be
+// allowed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OutboundRule {
+    pub protocol: String,
+    pub ports: String,
+    pub destinations: Targets,
+}
+
+impl OutboundRule {
+    /// Allows `protocol` traffic on `ports` to `destinations`
+    pub fn new(protocol: &str, ports: &str, destinations: Targets) -> OutboundRule {
+        OutboundRule {
+            protocol: protocol.to_owned(),
+            ports: ports.to_owned(),
+            destinations: destinations,
+        }
+    }
+}
+
+impl fmt::Display for OutboundRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{} to {}", self.protocol, self.ports, self.destinations)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PendingChange {
+    pub droplet_id: f64,
+    pub removing: bool,
+    pub status: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Firewall {
+    pub id: String,
+    pub name: String,
+    pub status: String,
+    pub created_at: String,
+    pub pending_changes: Vec<PendingChange>,
+    pub inbound_rules: Vec<InboundRule>,
+    pub outbound_rules: Vec<OutboundRule>,
+    pub droplet_ids: Vec<f64>,
+    pub tags: Vec<String>,
+}
+
+impl response::NotArray for Firewall {}
+
+impl fmt::Display for Firewall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "ID: {}\n\
+                Name: {}\n\
+                Status: {}\n\
+                Created At: {}\n\
+                Inbound Rules:{}\n\
+                Outbound Rules:{}\n\
+                Droplet IDs:{}\n\
+                Tags:{}\n\
+                Pending Changes: {}\n",
+               self.id,
+               self.name,
+               self.status,
+               self.created_at,
+               self.inbound_rules
+                   .iter()
+                   .fold(String::new(), |acc, r| acc + &format!(" {},", r)[..]),
+               self.outbound_rules
+                   .iter()
+                   .fold(String::new(), |acc, r| acc + &format!(" {},", r)[..]),
+               self.droplet_ids
+                   .iter()
+                   .fold(String::new(), |acc, id| acc + &format!(" {:.0},", id)[..]),
+               self.tags.iter().fold(String::new(), |acc, t| acc + &format!(" {},", t)[..]),
+               self.pending_changes.len())
+    }
+}
+
+pub type Firewalls = Vec<Firewall>;
+
+impl NamedResponse for Firewall {
+    fn name<'a>() -> Cow<'a, str> { "firewall".into() }
+}
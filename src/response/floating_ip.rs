@@ -0,0 +1,49 @@
+// ip               string      The public IP address of the Floating IP. It also serves as its
+// identifier.
+// region           object      The region that the Floating IP is reserved to. When you query a
+// Floating IP, the entire region object will be returned.
+// droplet          object      The Droplet that the Floating IP has been assigned to. When you
+// query a Floating IP, if it is assigned to a Droplet, the entire Droplet object will be
+// returned. If it is not assigned, the value will be null.
+// locked           boolean     A boolean value indicating whether or not the Floating IP has
+// pending actions preventing new ones from being submitted.
+
+use std::fmt;
+use std::borrow::Cow;
+
+use response::{Droplet, NamedResponse, Region};
+use response;
+
+#[derive(Deserialize, Debug)]
+pub struct FloatingIp {
+    pub ip: String,
+    pub region: Region,
+    pub droplet: Option<Droplet>,
+    pub locked: bool,
+}
+
+impl response::NotArray for FloatingIp {}
+
+impl fmt::Display for FloatingIp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "IP: {}\n\
+                Region: {}\n\
+                Droplet: {}\n\
+                Locked: {}\n",
+               self.ip,
+               self.region.slug,
+               if let Some(ref d) = self.droplet {
+                   format!("{:.0} ({})", d.id, d.name)
+               } else {
+                   "None".to_owned()
+               },
+               self.locked)
+    }
+}
+
+pub type FloatingIps = Vec<FloatingIp>;
+
+impl NamedResponse for FloatingIp {
+    fn name<'a>() -> Cow<'a, str> { "floating_ip".into() }
+}
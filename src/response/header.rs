@@ -7,11 +7,12 @@
 use std::borrow::Cow;
 use std::fmt;
 
-use hyper::client::response::Response;
-use hyper::header;
+use hyper::status::StatusCode;
 
 use response::NamedResponse;
 use response;
+use transport::HttpResponse;
+use Error;
 
 #[derive(Deserialize)]
 pub struct HeaderOnly {
@@ -29,39 +30,36 @@ pub struct HeaderOnly {
 impl response::NotArray for HeaderOnly {}
 
 impl HeaderOnly {
-    pub fn from_response(r: Response) -> Result<HeaderOnly, String> {
-        let c_type = match r.headers.get::<header::ContentType>() {
-            Some(c) => c.to_string(),
-            None => String::new(),
-        };
-        let raw_status = r.status_raw();
-        let status = format!("{} {}", raw_status.0, raw_status.1);
-        let rl_limit_raw = match r.headers.get_raw("ratelimit-limit") {
+    pub fn from_response(r: &HttpResponse) -> Result<HeaderOnly, Error> {
+        let c_type = r.header("content-type").unwrap_or("").to_owned();
+        let status = format!("{} {}",
+                             r.status,
+                             StatusCode::from_u16(r.status)
+                                 .canonical_reason()
+                                 .unwrap_or(""));
+        let rl_limit_str = match r.header("ratelimit-limit") {
             Some(c) => c,
-            None => return Err("No ratelimit-limit provided".to_owned()),
+            None => return Err(Error::Header("No ratelimit-limit provided".to_owned())),
         };
-        let rl_remain_raw = match r.headers.get_raw("ratelimit-remaining") {
+        let rl_remain_str = match r.header("ratelimit-remaining") {
             Some(c) => c,
-            None => return Err("No ratelimit-remaining provided".to_owned()),
+            None => return Err(Error::Header("No ratelimit-remaining provided".to_owned())),
         };
-        let rl_reset_raw = match r.headers.get_raw("ratelimit-reset") {
+        let rl_reset_str = match r.header("ratelimit-reset") {
             Some(c) => c,
-            None => return Err("No ratelimit-reset provided".to_owned()),
+            None => return Err(Error::Header("No ratelimit-reset provided".to_owned())),
         };
-        let rl_limit_str = String::from_utf8_lossy(&rl_limit_raw[0][..]);
-        let rl_remain_str = String::from_utf8_lossy(&rl_remain_raw[0][..]);
-        let rl_reset_str = String::from_utf8_lossy(&rl_reset_raw[0][..]);
-        let rl_limit = match rl_limit_str.as_ref().parse::<f64>() {
+        let rl_limit = match rl_limit_str.parse::<f64>() {
             Ok(n) => n,
-            Err(e) => return Err(e.to_string()),
+            Err(e) => return Err(Error::Header(e.to_string())),
         };
-        let rl_remain = match rl_remain_str.as_ref().parse::<f64>() {
+        let rl_remain = match rl_remain_str.parse::<f64>() {
             Ok(n) => n,
-            Err(e) => return Err(e.to_string()),
+            Err(e) => return Err(Error::Header(e.to_string())),
         };
-        let rl_reset = match rl_reset_str.as_ref().parse::<f64>() {
+        let rl_reset = match rl_reset_str.parse::<f64>() {
             Ok(n) => n,
-            Err(e) => return Err(e.to_string()),
+            Err(e) => return Err(Error::Header(e.to_string())),
         };
         Ok(HeaderOnly {
             content_type: c_type,
@@ -0,0 +1,342 @@
+// id               string      A unique ID that can be used to identify and reference a
+// Kubernetes cluster.
+// name             string      A human-readable name for a Kubernetes cluster.
+// region           string      The slug identifier for the region where the cluster is located.
+// version          string      The slug identifier for the version of Kubernetes used for the
+// cluster (i.e. "1.18.6-do.0").
+// cluster_subnet   string      The range of IP addresses in the overlay network of the cluster
+// in CIDR notation.
+// service_subnet   string      The range of assignable IP addresses for services running in the
+// cluster in CIDR notation.
+// vpc_uuid         string      The ID of the VPC where the cluster is located.
+// ipv4             string      The public IPv4 address of the Kubernetes master node.
+// endpoint         string      The base URL of the API server on the Kubernetes master node.
+// tags             array       The tags applied to the cluster.
+// node_pools       array       The node pools of the cluster.
+// auto_upgrade     boolean     A boolean value indicating whether the cluster will be
+// automatically upgraded to new patch releases during its maintenance window.
+// status           object      An object containing a "state" attribute ("running",
+// "provisioning", "degraded", "error", "deleted", "upgrading" or "deleting") and a "message".
+// created_at       string      A time value given in ISO8601 combined date and time format that
+// represents when the cluster was created.
+// updated_at       string      A time value given in ISO8601 combined date and time format that
+// represents when the cluster was last updated.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use response::NamedResponse;
+use response;
+use Error;
+
+#[derive(Deserialize, Debug)]
+pub struct KubernetesStatus {
+    pub state: String,
+    pub message: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct KubernetesCluster {
+    pub id: String,
+    pub name: String,
+    pub region: String,
+    pub version: String,
+    pub cluster_subnet: String,
+    pub service_subnet: String,
+    pub vpc_uuid: Option<String>,
+    pub ipv4: String,
+    pub endpoint: String,
+    pub tags: Vec<String>,
+    pub node_pools: Vec<NodePool>,
+    pub auto_upgrade: Option<bool>,
+    pub status: KubernetesStatus,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl response::NotArray for KubernetesCluster {}
+
+impl fmt::Display for KubernetesCluster {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "ID: {}\n\
+                Name: {}\n\
+                Region: {}\n\
+                Version: {}\n\
+                Status: {}\n\
+                Endpoint: {}\n\
+                Node Pools:{}\n\
+                Created At: {}\n",
+               self.id,
+               self.name,
+               self.region,
+               self.version,
+               self.status.state,
+               self.endpoint,
+               self.node_pools
+                   .iter()
+                   .fold(String::new(),
+                         |acc, p| acc + &format!(" {} ({:.0}),", p.name, p.count)[..]),
+               self.created_at)
+    }
+}
+
+pub type KubernetesClusters = Vec<KubernetesCluster>;
+
+impl NamedResponse for KubernetesCluster {
+    fn name<'a>() -> Cow<'a, str> { "kubernetes_cluster".into() }
+}
+
+// id           string      A unique ID that can be used to identify and reference a node pool.
+// name         string      A human-readable name for the node pool.
+// size         string      The slug identifier for the type of Droplet used as workers.
+// count        number      The number of Droplet instances in the node pool.
+// tags         array       The tags applied to the node pool.
+// auto_scale   boolean     A boolean value indicating whether auto-scaling is enabled.
+// min_nodes    number      The minimum number of nodes the pool can be scaled down to.
+// max_nodes    number      The maximum number of nodes the pool can be scaled up to.
+// nodes        array       The nodes in the pool.
+#[derive(Deserialize, Debug)]
+pub struct NodePool {
+    pub id: String,
+    pub name: String,
+    pub size: String,
+    pub count: f64,
+    pub tags: Vec<String>,
+    pub auto_scale: Option<bool>,
+    pub min_nodes: Option<f64>,
+    pub max_nodes: Option<f64>,
+    pub nodes: Vec<Node>,
+}
+
+impl response::NotArray for NodePool {}
+
+impl fmt::Display for NodePool {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "ID: {}\n\
+                Name: {}\n\
+                Size: {}\n\
+                Count: {:.0}\n\
+                Nodes:{}\n",
+               self.id,
+               self.name,
+               self.size,
+               self.count,
+               self.nodes.iter().fold(String::new(), |acc, n| acc + &format!(" {},", n.name)[..]))
+    }
+}
+
+pub type NodePools = Vec<NodePool>;
+
+impl NamedResponse for NodePool {
+    fn name<'a>() -> Cow<'a, str> { "node_pool".into() }
+}
+
+// id           string      A unique ID that can be used to identify and reference the node.
+// name         string      An automatically generated, human-readable name for the node.
+// status       object      An object containing a "state" attribute ("provisioning",
+// "running", "draining" or "deleting").
+// droplet_id   string      The ID of the Droplet used for the worker node.
+// created_at   string      A time value given in ISO8601 combined date and time format that
+// represents when the node was created.
+// updated_at   string      A time value given in ISO8601 combined date and time format that
+// represents when the node was last updated.
+#[derive(Deserialize, Debug)]
+pub struct Node {
+    pub id: String,
+    pub name: String,
+    pub status: KubernetesStatus,
+    pub droplet_id: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+// slug                 string      The slug identifier for an available version of Kubernetes
+// on DigitalOcean (i.e. "1.18.6-do.0").
+// kubernetes_version   string      The upstream version string for the version of Kubernetes
+// (i.e. "1.18.6").
+#[derive(Deserialize, Debug)]
+pub struct KubernetesVersion {
+    pub slug: String,
+    pub kubernetes_version: String,
+}
+
+impl response::NotArray for KubernetesVersion {}
+
+impl fmt::Display for KubernetesVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (Kubernetes {})", self.slug, self.kubernetes_version)
+    }
+}
+
+pub type KubernetesVersions = Vec<KubernetesVersion>;
+
+impl NamedResponse for KubernetesVersion {
+    fn name<'a>() -> Cow<'a, str> { "version".into() }
+
+    // The versions a cluster may be upgraded to are the only top level list of versions
+    fn collection_name<'a>() -> Cow<'a, str> { "available_upgrade_versions".into() }
+}
+
+// name         string      A human-readable name for the region or size.
+// slug         string      The slug identifier for the region or size.
+#[derive(Deserialize, Debug)]
+pub struct KubernetesOption {
+    pub name: String,
+    pub slug: String,
+}
+
+// regions      array       The regions Kubernetes clusters may be created in.
+// versions     array       The versions of Kubernetes clusters may be created with.
+// sizes        array       The sizes of Droplets which may be used for node pools.
+#[derive(Deserialize, Debug)]
+pub struct KubernetesOptions {
+    pub regions: Vec<KubernetesOption>,
+    pub versions: Vec<KubernetesVersion>,
+    pub sizes: Vec<KubernetesOption>,
+}
+
+impl response::NotArray for KubernetesOptions {}
+
+impl fmt::Display for KubernetesOptions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Regions:{}\n\
+                Versions:{}\n\
+                Sizes:{}\n",
+               self.regions.iter().fold(String::new(), |acc, r| acc + &format!(" {},", r.slug)[..]),
+               self.versions
+                   .iter()
+                   .fold(String::new(), |acc, v| acc + &format!(" {},", v.slug)[..]),
+               self.sizes.iter().fold(String::new(), |acc, s| acc + &format!(" {},", s.slug)[..]))
+    }
+}
+
+impl NamedResponse for KubernetesOptions {
+    fn name<'a>() -> Cow<'a, str> { "options".into() }
+}
+
+/// The kubeconfig of a cluster, as returned by DigitalOcean in YAML. `raw` can be written to
+/// disk as is; the other fields are read from the first cluster and user it contains.
+#[derive(Deserialize, Debug)]
+pub struct Kubeconfig {
+    pub raw: String,
+    /// The URL of the cluster's API server
+    pub server: String,
+    /// The base64 encoded certificate of the cluster's certificate authority
+    pub certificate_authority_data: String,
+    /// The bearer token, when the kubeconfig isn't using an `exec` credential plugin
+    pub token: Option<String>,
+    /// An estimate of the time (in UNIX epoch seconds) at which `token` expires. DigitalOcean
+    /// doesn't include the expiry in the kubeconfig, so this is the local clock at the time the
+    /// kubeconfig was parsed plus the `expiry_seconds` it was requested with (7 days unless
+    /// given). Clock skew and the time spent on the request make it slightly later than the
+    /// real expiry, so refresh the kubeconfig with some margin.
+    pub estimated_token_expiry: Option<f64>,
+}
+
+impl Kubeconfig {
+    /// Reads the server, certificate authority and token out of `yaml`, a kubeconfig which was
+    /// requested with a token lifetime of `expiry_seconds`, from which the token's expiry is
+    /// estimated
+    pub fn parse(yaml: String, expiry_seconds: u64) -> Result<Kubeconfig, Error> {
+        let server = match yaml_value(&yaml[..], "server") {
+            Some(s) => s,
+            None => return Err(Error::Invalid("kubeconfig has no server".to_owned())),
+        };
+        let ca = match yaml_value(&yaml[..], "certificate-authority-data") {
+            Some(s) => s,
+            None => {
+                return Err(Error::Invalid("kubeconfig has no certificate-authority-data"
+                                              .to_owned()))
+            }
+        };
+        let token = yaml_value(&yaml[..], "token");
+        let now = SystemTime::now()
+                      .duration_since(UNIX_EPOCH)
+                      .map(|d| d.as_secs())
+                      .unwrap_or(0);
+        let estimated_expiry = token.as_ref().map(|_| (now + expiry_seconds) as f64);
+        Ok(Kubeconfig {
+            raw: yaml,
+            server: server,
+            certificate_authority_data: ca,
+            token: token,
+            estimated_token_expiry: estimated_expiry,
+        })
+    }
+}
+
+// Returns the value of the first `key: value` line for `key`, which is enough for the flat
+// mappings of the kubeconfigs DigitalOcean generates
+fn yaml_value(yaml: &str, key: &str) -> Option<String> {
+    yaml.lines()
+        .map(|l| l.trim().trim_left_matches("- "))
+        .filter(|l| l.starts_with(key) && l[key.len()..].starts_with(':'))
+        .map(|l| l[key.len() + 1..].trim().trim_matches(|c| c == '"' || c == '\'').to_owned())
+        .find(|v| !v.is_empty())
+}
+
+impl response::NotArray for Kubeconfig {}
+
+impl fmt::Display for Kubeconfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.raw) }
+}
+
+impl NamedResponse for Kubeconfig {
+    fn name<'a>() -> Cow<'a, str> { "kubeconfig".into() }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use super::Kubeconfig;
+
+    fn now() -> f64 { SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as f64 }
+
+    const KUBECONFIG: &'static str = r#"apiVersion: v1
+clusters:
+- cluster:
+    certificate-authority-data: LS0tLS1CRUdJTiBDRVJUSUZJQ0FURS0tLS0t
+    server: https://bd5f5959-5e1e-4205-a714-a914373942af.k8s.ondigitalocean.com
+  name: do-nyc1-prod
+contexts:
+- context:
+    cluster: do-nyc1-prod
+    user: do-nyc1-prod-admin
+  name: do-nyc1-prod
+current-context: do-nyc1-prod
+kind: Config
+preferences: {}
+users:
+- name: do-nyc1-prod-admin
+  user:
+    token: "03a5e1b6f0e8c5d4"
+"#;
+
+    #[test]
+    fn parse() {
+        let before = now();
+        let config = Kubeconfig::parse(KUBECONFIG.to_owned(), 3600).unwrap();
+        let expiry = config.estimated_token_expiry.unwrap();
+        assert!(expiry >= before + 3600.0 && expiry <= now() + 3600.0);
+        assert_eq!(config.server,
+                   "https://bd5f5959-5e1e-4205-a714-a914373942af.k8s.ondigitalocean.com");
+        assert_eq!(config.certificate_authority_data, "LS0tLS1CRUdJTiBDRVJUSUZJQ0FURS0tLS0t");
+        assert_eq!(config.token, Some("03a5e1b6f0e8c5d4".to_owned()));
+        assert_eq!(config.raw, KUBECONFIG);
+    }
+
+    #[test]
+    fn exec_plugin() {
+        let yaml = KUBECONFIG.replace("    token: \"03a5e1b6f0e8c5d4\"\n",
+                                      "    exec:\n      command: doctl\n");
+        let config = Kubeconfig::parse(yaml, 3600).unwrap();
+        assert!(config.token.is_none());
+        assert!(config.estimated_token_expiry.is_none());
+        assert!(Kubeconfig::parse("kind: Config".to_owned(), 3600).is_err());
+    }
+}
@@ -0,0 +1,191 @@
+// id                       string      A unique ID that can be used to identify and reference a
+// Load Balancer.
+// name                     string      A human-readable name for a Load Balancer instance.
+// ip                       string      An attribute containing the public-facing IP address of
+// the Load Balancer.
+// algorithm                string      The load balancing algorithm used to determine which
+// backend Droplet will be selected by a client. It must be either "round_robin" or
+// "least_connections".
+// status                   string      A status string indicating the current state of the Load
+// Balancer. This can be "new", "active", or "errored".
+// created_at               string      A time value given in ISO8601 combined date and time
+// format that represents when the Load Balancer was created.
+// forwarding_rules         array       An array of objects specifying the forwarding rules for a
+// Load Balancer.
+// health_check             object      An object specifying health check settings for the Load
+// Balancer.
+// sticky_sessions          object      An object specifying sticky sessions settings for the
+// Load Balancer.
+// region                   object      The region where the Load Balancer instance is located.
+// tag                      string      The name of a Droplet tag corresponding to Droplets
+// assigned to the Load Balancer.
+// droplet_ids              array       An array containing the IDs of the Droplets assigned to
+// the Load Balancer.
+// redirect_http_to_https   bool        A boolean value indicating whether HTTP requests to the
+// Load Balancer on port 80 will be redirected to HTTPS on port 443.
+
+use std::fmt;
+use std::borrow::Cow;
+
+use response::{NamedResponse, Region};
+use response;
+
+// entry_protocol   string      The protocol used for traffic to the Load Balancer ("http",
+// "https", "http2" or "tcp").
+// entry_port       int         The port on which the Load Balancer instance will listen.
+// target_protocol  string      The protocol used for traffic from the Load Balancer to the
+// backend Droplets.
+// target_port      int         The port on the backend Droplets to which the Load Balancer
+// will send traffic.
+// certificate_id   string      The ID of the TLS certificate used for SSL termination if
+// enabled.
+// tls_passthrough  bool        A boolean indicating if SSL encrypted traffic will be passed
+// through to the backend Droplets.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ForwardingRule {
+    pub entry_protocol: String,
+    pub entry_port: u16,
+    pub target_protocol: String,
+    pub target_port: u16,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub certificate_id: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub tls_passthrough: Option<bool>,
+}
+
+impl ForwardingRule {
+    /// A rule forwarding `entry_protocol` traffic on `entry_port` to `target_protocol` on
+    /// `target_port`
+    pub fn new(entry_protocol: &str,
+               entry_port: u16,
+               target_protocol: &str,
+               target_port: u16)
+               -> ForwardingRule {
+        ForwardingRule {
+            entry_protocol: entry_protocol.to_owned(),
+            entry_port: entry_port,
+            target_protocol: target_protocol.to_owned(),
+            target_port: target_port,
+            certificate_id: None,
+            tls_passthrough: None,
+        }
+    }
+}
+
+impl fmt::Display for ForwardingRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "{}:{} -> {}:{}",
+               self.entry_protocol,
+               self.entry_port,
+               self.target_protocol,
+               self.target_port)
+    }
+}
+
+// protocol                     string  The protocol used for health checks sent to the backend
+// Droplets ("http" or "tcp").
+// port                         int     The port on the backend Droplets on which the health
+// check will attempt a connection.
+// path                         string  The path on the backend Droplets to which the Load
+// Balancer instance will send a request.
+// check_interval_seconds       int     The number of seconds between between two consecutive
+// health checks.
+// response_timeout_seconds     int     The number of seconds the Load Balancer instance will wait
+// for a response until marking a health check as failed.
+// healthy_threshold            int     The number of times a health check must pass for a
+// backend Droplet to be marked "healthy" and be re-added to the pool.
+// unhealthy_threshold          int     The number of times a health check must fail for a
+// backend Droplet to be marked "unhealthy" and be removed from the pool.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct HealthCheck {
+    pub protocol: String,
+    pub port: u16,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub check_interval_seconds: Option<u32>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub response_timeout_seconds: Option<u32>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub healthy_threshold: Option<u32>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub unhealthy_threshold: Option<u32>,
+}
+
+// type                 string  An attribute indicating how and if requests from a client will
+// be persistently served by the same backend Droplet ("cookies" or "none").
+// cookie_name          string  The name to be used for the cookie sent to the client.
+// cookie_ttl_seconds   int     The number of seconds until the cookie set by the Load Balancer
+// expires.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct StickySessions {
+    #[serde(rename="type")]
+    pub session_type: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub cookie_name: Option<String>,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub cookie_ttl_seconds: Option<u32>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct LoadBalancer {
+    pub id: String,
+    pub name: String,
+    pub ip: String,
+    pub algorithm: String,
+    pub status: String,
+    pub created_at: String,
+    pub forwarding_rules: Vec<ForwardingRule>,
+    pub health_check: HealthCheck,
+    pub sticky_sessions: StickySessions,
+    pub region: Region,
+    pub tag: String,
+    pub droplet_ids: Vec<f64>,
+    pub redirect_http_to_https: bool,
+}
+
+impl response::NotArray for LoadBalancer {}
+
+impl fmt::Display for LoadBalancer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "ID: {}\n\
+                Name: {}\n\
+                IP: {}\n\
+                Algorithm: {}\n\
+                Status: {}\n\
+                Created At: {}\n\
+                Forwarding Rules:{}\n\
+                Health Check: {}:{}\n\
+                Sticky Sessions: {}\n\
+                Region: {}\n\
+                Tag: {}\n\
+                Droplet IDs:{}\n\
+                Redirect HTTP to HTTPS: {}\n",
+               self.id,
+               self.name,
+               self.ip,
+               self.algorithm,
+               self.status,
+               self.created_at,
+               self.forwarding_rules
+                   .iter()
+                   .fold(String::new(), |acc, r| acc + &format!(" {},", r)[..]),
+               self.health_check.protocol,
+               self.health_check.port,
+               self.sticky_sessions.session_type,
+               self.region.slug,
+               self.tag,
+               self.droplet_ids
+                   .iter()
+                   .fold(String::new(), |acc, id| acc + &format!(" {:.0},", id)[..]),
+               self.redirect_http_to_https)
+    }
+}
+
+pub type LoadBalancers = Vec<LoadBalancer>;
+
+impl NamedResponse for LoadBalancer {
+    fn name<'a>() -> Cow<'a, str> { "load_balancer".into() }
+}
@@ -1,24 +1,55 @@
 pub use self::account::Account;
 pub use self::action::{Action, Actions};
+pub use self::app::{App, AppEnv, AppJob, AppLogs, AppProposal, AppRoute, AppService, AppSpec,
+                    AppStaticSite, AppWorker, Apps, Deployment, DeploymentProgress, Deployments,
+                    GitHubSource, GitSource, ImageSource};
 pub use self::backup::{Backup, Backups};
+pub use self::billing::{Balance, BillingHistory, BillingHistoryEntry, Invoice, InvoiceFile,
+                        InvoiceItem, InvoiceItems, InvoiceSummary, Invoices, ProductCharge,
+                        ProductCharges, SummaryAmount};
+pub use self::cdn_endpoint::{CdnEndpoint, CdnEndpoints};
+pub use self::certificate::{Certificate, Certificates};
+pub use self::database::{ConnectionPool, ConnectionPools, Database, DatabaseBackup,
+                         DatabaseBackups, DatabaseConnection, DatabaseDb, DatabaseDbs,
+                         DatabaseReplica, DatabaseReplicas, DatabaseSize, DatabaseUser,
+                         DatabaseUsers, Databases, Engine, MaintenanceWindow, TrustedSource,
+                         TrustedSources};
 pub use self::domain::{Domain, Domains};
 pub use self::dns::{DnsRecord, DnsRecords};
-pub use self::droplet::{Droplet, Droplets};
+pub use self::droplet::{Droplet, Droplets, TaggedDroplets};
 pub use self::error::DoError;
+pub use self::firewall::{Firewall, Firewalls, InboundRule, OutboundRule, PendingChange, Targets};
+pub use self::floating_ip::{FloatingIp, FloatingIps};
 pub use self::header::HeaderOnly;
 pub use self::image::{Image, Images};
 pub use self::kernel::{Kernel, Kernels};
+pub use self::kubernetes::{Kubeconfig, KubernetesCluster, KubernetesClusters, KubernetesOption,
+                           KubernetesOptions, KubernetesStatus, KubernetesVersion,
+                           KubernetesVersions, Node, NodePool, NodePools};
 pub use self::links::Links;
+pub use self::load_balancer::{ForwardingRule, HealthCheck, LoadBalancer, LoadBalancers,
+                              StickySessions};
 pub use self::meta::Meta;
+pub use self::monitoring::{AlertPolicies, AlertPolicy, AlertTargets, MetricSeries, Metrics,
+                           Monitoring, SlackTarget};
 pub use self::namedresponse::NamedResponse;
 pub use self::neighbors::Neighbors;
 pub use self::network::{Network, Networks};
 pub use self::page::{NewIter, Pages, RawPagedResponse};
-pub use self::region::{Region, Regions};
+pub use self::project::{Project, ProjectResource, ProjectResources, Projects};
+pub use self::region::{Region, RegionSlug, Regions};
+pub use self::registry::{DockerCredentials, GarbageCollection, GarbageCollections, Registry,
+                         Repositories, Repository, RepositoryTag, RepositoryTags};
 pub use self::size::{Size, Sizes};
 pub use self::snapshot::{Snapshot, Snapshots};
 pub use self::ssh_key::{SshKey, SshKeys};
+pub use self::tag::{Tag, TagResources, TaggedResources, Tags};
 pub use self::upgrades::{DropletUpgrade, DropletUpgrades, ResponseStringArray};
+pub use self::uptime::{Outage, RegionState, UptimeAlert, UptimeAlerts, UptimeCheck,
+                       UptimeCheckState, UptimeChecks};
+pub use self::urn::Urn;
+pub use self::volume::{Volume, VolumeSnapshot, VolumeSnapshots, Volumes};
+pub use self::vpc::{Vpc, VpcMember, VpcMembers, Vpcs};
 
 mod account;
 mod header;
@@ -42,5 +73,22 @@ mod snapshot;
 mod region;
 mod size;
 mod upgrades;
+mod volume;
+mod floating_ip;
+mod load_balancer;
+mod firewall;
+mod tag;
+mod vpc;
+mod project;
+mod urn;
+mod certificate;
+mod cdn_endpoint;
+mod database;
+mod kubernetes;
+mod registry;
+mod monitoring;
+mod app;
+mod uptime;
+mod billing;
 
 pub trait NotArray {}
@@ -0,0 +1,144 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use response::NamedResponse;
+use response;
+
+/// Types the requests returned by `DoManager::monitoring()`, which can't be retrieved
+/// themselves but lead to metrics and alert policies
+pub enum Monitoring {}
+
+// resultType   string      The type of the result, always "matrix" for droplet metrics.
+// result       array       One series per set of labels (i.e. per CPU mode), each holding
+// `[timestamp, "value"]` pairs.
+#[derive(Deserialize, Debug)]
+pub struct Metrics {
+    #[serde(rename="resultType")]
+    pub result_type: String,
+    pub result: Vec<MetricSeries>,
+}
+
+impl response::NotArray for Metrics {}
+
+impl fmt::Display for Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for series in &self.result {
+            try!(write!(f, "{}\n", series));
+        }
+        Ok(())
+    }
+}
+
+impl NamedResponse for Metrics {
+    // The series are wrapped in `{"status": "success", "data": {...}}`
+    fn name<'a>() -> Cow<'a, str> { "data".into() }
+}
+
+// metric       object      The labels of the series (i.e. "host_id" and "mode").
+// values       array       `[timestamp, "value"]` pairs, the timestamp being in UNIX epoch
+// seconds and the value a decimal string.
+#[derive(Deserialize, Debug)]
+pub struct MetricSeries {
+    pub metric: BTreeMap<String, String>,
+    pub values: Vec<(f64, String)>,
+}
+
+impl MetricSeries {
+    /// Returns the `(timestamp, value)` pairs of the series with the values parsed, skipping any
+    /// value which isn't a number (i.e. `"NaN"`)
+    pub fn points(&self) -> Vec<(f64, f64)> {
+        self.values
+            .iter()
+            .filter_map(|&(t, ref v)| v.parse::<f64>().ok().map(|v| (t, v)))
+            .filter(|&(_, v)| !v.is_nan())
+            .collect()
+    }
+}
+
+impl fmt::Display for MetricSeries {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Labels:{}\n\
+                Points: {}\n",
+               self.metric
+                   .iter()
+                   .fold(String::new(), |acc, (k, v)| acc + &format!(" {}={},", k, v)[..]),
+               self.values.len())
+    }
+}
+
+// channel      string      Slack channel to notify of an alert trigger.
+// url          string      Slack Webhook URL.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SlackTarget {
+    pub channel: String,
+    pub url: String,
+}
+
+// email        array       The email addresses to notify, which must be verified.
+// slack        array       The Slack channels to notify.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct AlertTargets {
+    pub email: Vec<String>,
+    pub slack: Vec<SlackTarget>,
+}
+
+// uuid         string      A unique ID that can be used to identify and reference the policy.
+// type         string      The metric the policy watches (i.e. "v1/insights/droplet/cpu" or
+// "v1/insights/droplet/memory_utilization_percent").
+// description  string      A description of the policy.
+// compare      string      "GreaterThan" or "LessThan".
+// value        number      The threshold the metric is compared to.
+// window       string      The period the metric has to be above or below the threshold for
+// ("5m", "10m", "30m" or "1h").
+// entities     array       The IDs of the droplets the policy applies to.
+// tags         array       The droplet tags the policy applies to.
+// alerts       object      The email addresses and Slack channels to notify.
+// enabled      boolean     Whether the policy is active.
+#[derive(Deserialize, Debug)]
+pub struct AlertPolicy {
+    pub uuid: String,
+    #[serde(rename="type")]
+    pub policy_type: String,
+    pub description: String,
+    pub compare: String,
+    pub value: f64,
+    pub window: String,
+    pub entities: Vec<String>,
+    pub tags: Vec<String>,
+    pub alerts: AlertTargets,
+    pub enabled: bool,
+}
+
+impl response::NotArray for AlertPolicy {}
+
+impl fmt::Display for AlertPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "UUID: {}\n\
+                Type: {}\n\
+                Description: {}\n\
+                Condition: {} {} for {}\n\
+                Droplets:{}\n\
+                Tags:{}\n\
+                Enabled: {}\n",
+               self.uuid,
+               self.policy_type,
+               self.description,
+               self.compare,
+               self.value,
+               self.window,
+               self.entities.iter().fold(String::new(), |acc, s| acc + &format!(" {},", s)[..]),
+               self.tags.iter().fold(String::new(), |acc, s| acc + &format!(" {},", s)[..]),
+               self.enabled)
+    }
+}
+
+pub type AlertPolicies = Vec<AlertPolicy>;
+
+impl NamedResponse for AlertPolicy {
+    fn name<'a>() -> Cow<'a, str> { "policy".into() }
+
+    fn collection_name<'a>() -> Cow<'a, str> { "policies".into() }
+}
@@ -1,17 +1,22 @@
 use std::borrow::Cow;
 
 pub trait NamedResponse {
+    /// The key DigitalOcean uses for a single object of this type (i.e. `"droplet"`)
     fn name<'a>() -> Cow<'a, str>;
-}
 
-impl<T> NamedResponse for Vec<T>
-    where T: NamedResponse
-{
-    fn name<'a>() -> Cow<'a, str> {
+    /// The key DigitalOcean uses for a list of objects of this type (i.e. `"droplets"`). Defaults
+    /// to `name()` with an `s` appended.
+    fn collection_name<'a>() -> Cow<'a, str> {
         let mut n = String::with_capacity(15);
-        n.push_str(<T as NamedResponse>::name().as_ref());
+        n.push_str(Self::name().as_ref());
         n.push_str("s");
         n.shrink_to_fit();
         n.into()
     }
 }
+
+impl<T> NamedResponse for Vec<T>
+    where T: NamedResponse
+{
+    fn name<'a>() -> Cow<'a, str> { <T as NamedResponse>::collection_name() }
+}
@@ -24,6 +24,7 @@ pub struct DropletNeighbor {
     pub size: Size,
     pub size_slug: String,
     pub networks: Networks,
+    pub vpc_uuid: Option<String>,
 }
 
 impl fmt::Display for DropletNeighbor {
@@ -46,7 +47,8 @@ impl fmt::Display for DropletNeighbor {
                   Size Slug: {}\n\
                   Network: \n\t{}\n\
                   Kernel: \n\t{}\n\
-                  Next Backup Window: {}\n",
+                  Next Backup Window: {}\n\
+                  VPC: {}\n",
                self.id,
                self.name,
                self.memory,
@@ -92,7 +94,8 @@ impl fmt::Display for DropletNeighbor {
                    format!("{}", &k.to_string()[..].replace("\n", "\n\t"))
                } else {
                    "None".to_owned()
-               })
+               },
+               self.vpc_uuid.as_ref().map(|s| &s[..]).unwrap_or("None"))
 
     }
 }
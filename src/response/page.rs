@@ -1,4 +1,8 @@
-use response::{Links, Meta};
+use serde::de::{Deserialize, Error as DeError};
+use serde_json::{self, Value};
+
+use response::{Links, Meta, NamedResponse};
+use error::{self, Error};
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct Pages {
@@ -8,13 +12,55 @@ pub struct Pages {
     pub last: Option<String>,
 }
 
-#[derive(Deserialize)]
+/// A single page of a list response. DigitalOcean keys the list by the type it holds (i.e.
+/// `"droplets"` or `"domain_records"`), so the key is taken from `T::collection_name()`.
+///
+/// Some lists (such as the actions returned by tag-scoped droplet actions) aren't paginated and
+/// have no `links` or `meta`, in which case they are treated as the only page.
 pub struct RawPagedResponse<T> {
     pub collection: Vec<T>,
     pub links: Links,
     pub meta: Meta,
 }
 
+impl<T> RawPagedResponse<T>
+    where T: Deserialize + NamedResponse
+{
+    /// Parses a page from the response body `s`. Items are deserialized one at a time, so an
+    /// item which fails is reported by its index (i.e. `/droplets/3`).
+    pub fn from_json(s: &str) -> Result<RawPagedResponse<T>, Error> {
+        let mut v = try!(serde_json::from_str::<Value>(s));
+        let name = <T as NamedResponse>::collection_name().into_owned();
+        let (collection, links, meta) = match v.as_object_mut() {
+            Some(m) => (m.remove(&name), m.remove("links"), m.remove("meta")),
+            None => (None, None, None),
+        };
+        let path = format!("/{}", name);
+        let collection: Vec<T> = match collection {
+            Some(c) => try!(error::decode_seq(c, &path[..])),
+            None => {
+                return Err(Error::Deserialize {
+                    path: path,
+                    cause: serde_json::Error::custom(format!("missing field `{}`", name)),
+                })
+            }
+        };
+        let links = match links {
+            Some(l) => try!(error::decode(l, "/links")),
+            None => Links { pages: None },
+        };
+        let meta = match meta {
+            Some(m) => try!(error::decode(m, "/meta")),
+            None => Meta { total: collection.len() as f64 },
+        };
+        Ok(RawPagedResponse {
+            collection: collection,
+            links: links,
+            meta: meta,
+        })
+    }
+}
+
 pub trait NewIter {
     type Item;
     fn new() -> Vec<Self::Item> { vec![] }
@@ -25,3 +71,151 @@ impl<R> NewIter for R
 {
     type Item = <Self as Iterator>::Item;
 }
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+    use serde_json::{self, Value};
+
+    use response::*;
+    use testing::fixtures;
+    use Error;
+
+    const NEXT: &'static str = "https://api.digitalocean.com/v2/x?page=2";
+
+    // Wraps `items` the way DigitalOcean does for the first of two pages
+    fn page_json<T: NamedResponse>(items: Vec<Value>) -> String {
+        format!("{{\"{}\": {}, \"links\": {{\"pages\": {{\"next\": \"{}\"}}}}, \
+                   \"meta\": {{\"total\": 40}}}}",
+                T::collection_name(),
+                fixtures::array(items),
+                NEXT)
+    }
+
+    fn parse<T: Deserialize + NamedResponse>(items: Vec<Value>) -> RawPagedResponse<T> {
+        let page = RawPagedResponse::<T>::from_json(&page_json::<T>(items)[..]).unwrap();
+        assert_eq!(page.meta.total, 40.0);
+        assert_eq!(page.links.pages.and_then(|p| p.next), Some(NEXT.to_owned()));
+        page
+    }
+
+    fn json(s: &str) -> Value { serde_json::from_str(s).unwrap() }
+
+    #[test]
+    fn actions() {
+        let page = parse::<Action>(vec![fixtures::action(1, "completed", "reboot", 2, "droplet",
+                                                         "nyc3")]);
+        assert_eq!(page.collection[0].action_type, "reboot");
+    }
+
+    #[test]
+    fn backups() {
+        let page = parse::<Backup>(vec![json(r#"{"id": 7, "name": "nightly", "type": "backup",
+            "distribution": "Ubuntu", "slug": null, "public": false, "regions": ["nyc3"],
+            "min_disk_size": 20}"#)]);
+        assert_eq!(page.collection[0].name, "nightly");
+    }
+
+    #[test]
+    fn dns_records() {
+        // A record whose data mentions another collection's key must be left untouched
+        let page = parse::<DnsRecord>(vec![json(r#"{"id": 3, "type": "TXT", "name": "@",
+            "data": "\"droplets\": \"domain_records\"", "priority": null, "port": null,
+            "weight": null}"#)]);
+        assert_eq!(page.collection[0].data, "\"droplets\": \"domain_records\"");
+        assert_eq!(DnsRecord::collection_name(), "domain_records");
+    }
+
+    #[test]
+    fn domains() {
+        let page = parse::<Domain>(vec![fixtures::domain("example.com", "10.0.0.1")]);
+        assert_eq!(page.collection[0].name, "example.com");
+    }
+
+    #[test]
+    fn droplets() {
+        let image = fixtures::image(1, "14.04 x64", Some("ubuntu-14-04-x64"), true, "snapshot",
+                                    "nyc3");
+        let page = parse::<Droplet>(vec![fixtures::droplet(2, "droplets", "nyc3", "512mb", image)]);
+        assert_eq!(page.collection[0].name, "droplets");
+    }
+
+    #[test]
+    fn droplet_upgrades() {
+        let page = parse::<DropletUpgrade>(vec![json(r#"{"droplet_id": 2,
+            "date_of_migration": "2016-12-01T00:00:00Z",
+            "url": "https://api.digitalocean.com/v2/droplets/2"}"#)]);
+        assert_eq!(page.collection.len(), 1);
+    }
+
+    #[test]
+    fn images() {
+        let page = parse::<Image>(vec![fixtures::image(1, "14.04 x64", None, false, "snapshot",
+                                                       "nyc3")]);
+        assert_eq!(page.collection[0].name, "14.04 x64");
+    }
+
+    #[test]
+    fn kernels() {
+        let page = parse::<Kernel>(vec![json(r#"{"id": 2233,
+            "name": "Ubuntu 14.04 x64 vmlinuz-3.13.0-37-generic",
+            "version": "3.13.0-37-generic"}"#)]);
+        assert_eq!(page.collection[0].version, "3.13.0-37-generic");
+    }
+
+    #[test]
+    fn regions() {
+        let page = parse::<Region>(vec![fixtures::region("nyc3")]);
+        assert_eq!(page.collection[0].slug, "nyc3");
+    }
+
+    #[test]
+    fn sizes() {
+        let page = parse::<Size>(vec![fixtures::size("1gb")]);
+        assert_eq!(page.collection[0].slug, "1gb");
+    }
+
+    #[test]
+    fn snapshots() {
+        let page = parse::<Snapshot>(vec![json(r#"{"id": 8, "name": "before-upgrade",
+            "type": "snapshot", "distribution": "Ubuntu", "slug": null, "public": false,
+            "regions": ["nyc3"], "min_disk_size": 20}"#)]);
+        assert_eq!(page.collection[0].name, "before-upgrade");
+    }
+
+    #[test]
+    fn ssh_keys() {
+        let page = parse::<SshKey>(vec![fixtures::ssh_key(4, "laptop", "ssh-rsa AAAAB3Nza")]);
+        assert_eq!(page.collection[0].name, "laptop");
+    }
+
+    #[test]
+    fn unpaged() {
+        let s = format!("{{\"actions\": {}}}",
+                        fixtures::array(vec![fixtures::action(1, "in-progress", "power_off", 2,
+                                                              "droplet", "nyc3")]));
+        let page = RawPagedResponse::<Action>::from_json(&s[..]).unwrap();
+        assert_eq!(page.meta.total, 1.0);
+        assert!(page.links.pages.is_none());
+    }
+
+    #[test]
+    fn wrong_key() {
+        let s = page_json::<Droplet>(vec![]);
+        match RawPagedResponse::<Image>::from_json(&s[..]) {
+            Err(Error::Deserialize { ref path, .. }) => assert_eq!(path, "/images"),
+            _ => panic!("expected a deserialize error"),
+        }
+    }
+
+    #[test]
+    fn bad_item_path() {
+        let s = page_json::<Region>(vec![fixtures::region("nyc3"),
+                                         fixtures::region("ams3"),
+                                         json(r#"{"slug": 3}"#)]);
+        match RawPagedResponse::<Region>::from_json(&s[..]) {
+            Err(Error::Deserialize { ref path, .. }) => assert_eq!(path, "/regions/2"),
+            _ => panic!("expected a deserialize error"),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// id               string      The unique universal identifier of this project.
+// owner_uuid       string      The unique universal identifier of the project owner.
+// owner_id         number      The integer id of the project owner.
+// name             string      The human-readable name for the project. The maximum length is
+// 175 characters and the name must be unique.
+// description      string      The description of the project. The maximum length is 255
+// characters.
+// purpose          string      The purpose of the project (i.e. "Web Application").
+// environment      string      The environment of the project's resources ("Development",
+// "Staging" or "Production").
+// is_default       boolean     If true, all resources will be added to this project if no
+// project is specified.
+// created_at       string      A time value given in ISO8601 combined date and time format that
+// represents when the project was created.
+// updated_at       string      A time value given in ISO8601 combined date and time format that
+// represents when the project was updated.
+
+use std::fmt;
+use std::borrow::Cow;
+
+use response::NamedResponse;
+use response;
+
+#[derive(Deserialize, Debug)]
+pub struct Project {
+    pub id: String,
+    pub owner_uuid: String,
+    pub owner_id: f64,
+    pub name: String,
+    pub description: Option<String>,
+    pub purpose: String,
+    pub environment: Option<String>,
+    pub is_default: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl response::NotArray for Project {}
+
+impl fmt::Display for Project {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "ID: {}\n\
+                Name: {}\n\
+                Description: {}\n\
+                Purpose: {}\n\
+                Environment: {}\n\
+                Default: {}\n\
+                Owner: {}\n\
+                Created At: {}\n\
+                Updated At: {}\n",
+               self.id,
+               self.name,
+               self.description.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.purpose,
+               self.environment.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.is_default,
+               self.owner_uuid,
+               self.created_at,
+               self.updated_at)
+    }
+}
+
+pub type Projects = Vec<Project>;
+
+impl NamedResponse for Project {
+    fn name<'a>() -> Cow<'a, str> { "project".into() }
+}
+
+// urn              string      The uniform resource name (URN) of the resource (i.e.
+// "do:droplet:13457723").
+// assigned_at      string      A time value given in ISO8601 combined date and time format that
+// represents when the resource was assigned to the project.
+// status           string      The status of assigning the resource to the project ("ok",
+// "not_found", "assigned", "already_assigned" or "service_down").
+
+#[derive(Deserialize, Debug)]
+pub struct ProjectResource {
+    pub urn: String,
+    pub assigned_at: Option<String>,
+    pub status: Option<String>,
+}
+
+impl response::NotArray for ProjectResource {}
+
+impl fmt::Display for ProjectResource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "URN: {}\n\
+                Assigned At: {}\n\
+                Status: {}\n",
+               self.urn,
+               self.assigned_at.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.status.as_ref().map(|s| &s[..]).unwrap_or("None"))
+    }
+}
+
+pub type ProjectResources = Vec<ProjectResource>;
+
+impl NamedResponse for ProjectResource {
+    fn name<'a>() -> Cow<'a, str> { "resource".into() }
+}
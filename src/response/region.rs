@@ -12,6 +12,8 @@
 use std::fmt;
 use std::borrow::Cow;
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 use response::NamedResponse;
 use response;
 
@@ -26,6 +28,43 @@ pub struct Region {
 
 impl response::NotArray for Region {}
 
+/// The slug identifying a region (i.e. `"nyc3"`), as accepted by the endpoints which place a
+/// resource in a region
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegionSlug(String);
+
+impl RegionSlug {
+    pub fn as_str(&self) -> &str { &self.0[..] }
+}
+
+impl<'a> From<&'a str> for RegionSlug {
+    fn from(s: &'a str) -> RegionSlug { RegionSlug(s.to_owned()) }
+}
+
+impl<'a> From<&'a Region> for RegionSlug {
+    fn from(r: &'a Region) -> RegionSlug { RegionSlug(r.slug.clone()) }
+}
+
+impl fmt::Display for RegionSlug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.0) }
+}
+
+impl Serialize for RegionSlug {
+    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
+        where S: Serializer
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Deserialize for RegionSlug {
+    fn deserialize<D>(deserializer: &mut D) -> Result<RegionSlug, D::Error>
+        where D: Deserializer
+    {
+        Ok(RegionSlug(try!(String::deserialize(deserializer))))
+    }
+}
+
 impl fmt::Display for Region {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f,
@@ -0,0 +1,223 @@
+// name                             string  A globally unique name for the container registry.
+// storage_usage_bytes              number  The amount of storage used in the registry in bytes.
+// storage_usage_bytes_updated_at   string  The time at which the storage usage was updated.
+// created_at                       string  A time value given in ISO8601 combined date and time
+// format that represents when the registry was created.
+// region                           string  The slug identifier of the region where the registry
+// data is stored.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use serde_json::{self, Value};
+
+use response::NamedResponse;
+use response;
+use Error;
+
+#[derive(Deserialize, Debug)]
+pub struct Registry {
+    pub name: String,
+    pub storage_usage_bytes: Option<f64>,
+    pub storage_usage_bytes_updated_at: Option<String>,
+    pub created_at: String,
+    pub region: Option<String>,
+}
+
+impl response::NotArray for Registry {}
+
+impl fmt::Display for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Name: {}\n\
+                Region: {}\n\
+                Storage Used: {:.0} bytes\n\
+                Created At: {}\n",
+               self.name,
+               self.region.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.storage_usage_bytes.unwrap_or(0.0),
+               self.created_at)
+    }
+}
+
+impl NamedResponse for Registry {
+    fn name<'a>() -> Cow<'a, str> { "registry".into() }
+}
+
+// registry_name            string  The name of the container registry.
+// repository               string  The name of the repository.
+// tag                      string  The name of the tag.
+// manifest_digest          string  The digest of the manifest associated with the tag.
+// compressed_size_bytes    number  The compressed size of the tag in bytes.
+// size_bytes               number  The uncompressed size of the tag in bytes.
+// updated_at               string  The time the tag was last updated.
+#[derive(Deserialize, Debug)]
+pub struct RepositoryTag {
+    pub registry_name: String,
+    pub repository: String,
+    pub tag: String,
+    pub manifest_digest: String,
+    pub compressed_size_bytes: f64,
+    pub size_bytes: f64,
+    pub updated_at: String,
+}
+
+impl response::NotArray for RepositoryTag {}
+
+impl fmt::Display for RepositoryTag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Tag: {}/{}:{}\n\
+                Manifest Digest: {}\n\
+                Size: {:.0} bytes ({:.0} compressed)\n\
+                Updated At: {}\n",
+               self.registry_name,
+               self.repository,
+               self.tag,
+               self.manifest_digest,
+               self.size_bytes,
+               self.compressed_size_bytes,
+               self.updated_at)
+    }
+}
+
+pub type RepositoryTags = Vec<RepositoryTag>;
+
+impl NamedResponse for RepositoryTag {
+    fn name<'a>() -> Cow<'a, str> { "tag".into() }
+}
+
+// registry_name    string      The name of the container registry.
+// name             string      The name of the repository.
+// latest_tag       object      The most recently updated tag of the repository.
+// tag_count        number      The number of tags in the repository.
+#[derive(Deserialize, Debug)]
+pub struct Repository {
+    pub registry_name: String,
+    pub name: String,
+    pub latest_tag: Option<RepositoryTag>,
+    pub tag_count: f64,
+}
+
+impl response::NotArray for Repository {}
+
+impl fmt::Display for Repository {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Name: {}/{}\n\
+                Tags: {:.0}\n\
+                Latest Tag: {}\n",
+               self.registry_name,
+               self.name,
+               self.tag_count,
+               self.latest_tag.as_ref().map(|t| &t.tag[..]).unwrap_or("None"))
+    }
+}
+
+pub type Repositories = Vec<Repository>;
+
+impl NamedResponse for Repository {
+    fn name<'a>() -> Cow<'a, str> { "repository".into() }
+
+    fn collection_name<'a>() -> Cow<'a, str> { "repositories".into() }
+}
+
+// uuid             string      A string specifying the UUID of the garbage collection.
+// registry_name    string      The name of the container registry.
+// status           string      The current status of the garbage collection ("requested",
+// "waiting for write JWTs to expire", "scanning manifests", "deleting unreferenced blobs",
+// "cancelling", "failed", "succeeded" or "cancelled").
+// created_at       string      The time the garbage collection was created.
+// updated_at       string      The time the garbage collection was last updated.
+// blobs_deleted    number      The number of blobs deleted as a result of the garbage
+// collection.
+// freed_bytes      number      The number of bytes freed as a result of the garbage collection.
+#[derive(Deserialize, Debug)]
+pub struct GarbageCollection {
+    pub uuid: String,
+    pub registry_name: String,
+    pub status: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub blobs_deleted: f64,
+    pub freed_bytes: f64,
+}
+
+impl response::NotArray for GarbageCollection {}
+
+impl fmt::Display for GarbageCollection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "UUID: {}\n\
+                Registry: {}\n\
+                Status: {}\n\
+                Blobs Deleted: {:.0}\n\
+                Freed: {:.0} bytes\n\
+                Created At: {}\n\
+                Updated At: {}\n",
+               self.uuid,
+               self.registry_name,
+               self.status,
+               self.blobs_deleted,
+               self.freed_bytes,
+               self.created_at,
+               self.updated_at)
+    }
+}
+
+pub type GarbageCollections = Vec<GarbageCollection>;
+
+impl NamedResponse for GarbageCollection {
+    fn name<'a>() -> Cow<'a, str> { "garbage_collection".into() }
+}
+
+/// A docker config (`~/.docker/config.json`) granting access to the registry. `raw` can be
+/// written to disk as is, `server` and `auth` are read from its only entry.
+#[derive(Deserialize, Debug)]
+pub struct DockerCredentials {
+    pub raw: String,
+    /// The registry host (i.e. `registry.digitalocean.com`)
+    pub server: String,
+    /// The base64 encoded `user:password` pair
+    pub auth: String,
+}
+
+impl DockerCredentials {
+    /// Reads the first entry of `auths` out of a docker config
+    pub fn parse(raw: String) -> Result<DockerCredentials, Error> {
+        let (server, auth) = {
+            let config = try!(serde_json::from_str::<Value>(&raw[..]));
+            let entry = config.find("auths")
+                              .and_then(|a| a.as_object())
+                              .and_then(|a| a.iter().next());
+            match entry {
+                Some((server, v)) => {
+                    match v.find("auth").and_then(|a| a.as_string()) {
+                        Some(auth) => (server.clone(), auth.to_owned()),
+                        None => {
+                            return Err(Error::Invalid(format!("no auth for '{}' in docker \
+                                                               credentials",
+                                                              server)))
+                        }
+                    }
+                }
+                None => return Err(Error::Invalid("docker credentials have no auths".to_owned())),
+            }
+        };
+        Ok(DockerCredentials {
+            raw: raw,
+            server: server,
+            auth: auth,
+        })
+    }
+}
+
+impl response::NotArray for DockerCredentials {}
+
+impl fmt::Display for DockerCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.raw) }
+}
+
+impl NamedResponse for DockerCredentials {
+    fn name<'a>() -> Cow<'a, str> { "auths".into() }
+}
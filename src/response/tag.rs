@@ -0,0 +1,46 @@
+// name         string      The name of the tag. Tags may contain letters, numbers, colons, dashes,
+// and underscores. There is a limit of 255 characters per tag.
+// resources    object      An embedded object containing key value pairs of resource type and
+// resource statistics, i.e. "droplets" with the number of tagged Droplets ("count") and the most
+// recently tagged Droplet ("last_tagged").
+
+use std::fmt;
+use std::borrow::Cow;
+
+use response::{Droplet, NamedResponse};
+use response;
+
+#[derive(Deserialize, Debug)]
+pub struct TaggedResources {
+    pub count: f64,
+    pub last_tagged: Option<Droplet>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TagResources {
+    pub droplets: Option<TaggedResources>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Tag {
+    pub name: String,
+    pub resources: TagResources,
+}
+
+impl response::NotArray for Tag {}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Name: {}\n\
+                Tagged Droplets: {:.0}\n",
+               self.name,
+               self.resources.droplets.as_ref().map(|d| d.count).unwrap_or(0.0))
+    }
+}
+
+pub type Tags = Vec<Tag>;
+
+impl NamedResponse for Tag {
+    fn name<'a>() -> Cow<'a, str> { "tag".into() }
+}
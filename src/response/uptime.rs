@@ -0,0 +1,161 @@
+// id               string      A unique ID that can be used to identify and reference the check.
+// name             string      A human-readable name for the check.
+// type             string      "ping", "http" or "https".
+// target           string      The endpoint to check (a host name for "ping", otherwise a URL).
+// regions          array       The regions the check is run from ("us_east", "us_west",
+// "eu_west" or "se_asia").
+// enabled          boolean     Whether the check is running.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use response::{AlertTargets, NamedResponse};
+use response;
+
+#[derive(Deserialize, Debug)]
+pub struct UptimeCheck {
+    pub id: String,
+    pub name: String,
+    #[serde(rename="type")]
+    pub check_type: String,
+    pub target: String,
+    pub regions: Vec<String>,
+    pub enabled: bool,
+}
+
+impl response::NotArray for UptimeCheck {}
+
+impl fmt::Display for UptimeCheck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "ID: {}\n\
+                Name: {}\n\
+                Type: {}\n\
+                Target: {}\n\
+                Regions:{}\n\
+                Enabled: {}\n",
+               self.id,
+               self.name,
+               self.check_type,
+               self.target,
+               self.regions.iter().fold(String::new(), |acc, s| acc + &format!(" {},", s)[..]),
+               self.enabled)
+    }
+}
+
+pub type UptimeChecks = Vec<UptimeCheck>;
+
+impl NamedResponse for UptimeCheck {
+    fn name<'a>() -> Cow<'a, str> { "check".into() }
+}
+
+// status           string      "UP" or "DOWN".
+// status_changed_at string     A time value given in ISO8601 combined date and time format that
+// represents when the status last changed.
+// thirty_day_uptime_percentage number The uptime over the last 30 days.
+#[derive(Deserialize, Debug)]
+pub struct RegionState {
+    pub status: String,
+    pub status_changed_at: Option<String>,
+    pub thirty_day_uptime_percentage: Option<f64>,
+}
+
+// region           string      The region the outage was seen from.
+// started_at       string      When the outage started.
+// ended_at         string      When the outage ended.
+// duration_seconds number      How long the outage lasted.
+#[derive(Deserialize, Debug)]
+pub struct Outage {
+    pub region: String,
+    pub started_at: String,
+    pub ended_at: Option<String>,
+    pub duration_seconds: Option<f64>,
+}
+
+// regions          object      The status of the check per region.
+// previous_outage  object      The latest outage, if there was one.
+#[derive(Deserialize, Debug)]
+pub struct UptimeCheckState {
+    pub regions: BTreeMap<String, RegionState>,
+    pub previous_outage: Option<Outage>,
+}
+
+impl UptimeCheckState {
+    /// Returns `true` if the check is up in every region
+    pub fn is_up(&self) -> bool { self.regions.values().all(|r| r.status == "UP") }
+}
+
+impl response::NotArray for UptimeCheckState {}
+
+impl fmt::Display for UptimeCheckState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Regions:{}\n\
+                Previous Outage: {}\n",
+               self.regions
+                   .iter()
+                   .fold(String::new(),
+                         |acc, (name, r)| acc + &format!(" {} ({}),", name, r.status)[..]),
+               self.previous_outage
+                   .as_ref()
+                   .map(|o| format!("{} in {}", o.started_at, o.region))
+                   .unwrap_or("None".to_owned()))
+    }
+}
+
+impl NamedResponse for UptimeCheckState {
+    fn name<'a>() -> Cow<'a, str> { "state".into() }
+}
+
+// id               string      A unique ID that can be used to identify and reference the alert.
+// name             string      A human-readable name for the alert.
+// type             string      "latency", "down", "down_global" or "ssl_expiry".
+// threshold        number      The value the alert triggers at (milliseconds for "latency",
+// days for "ssl_expiry").
+// comparison       string      "greater_than" or "less_than".
+// notifications    object      The email addresses and Slack channels to notify.
+// period           string      How long the threshold has to be crossed for ("2m", "3m", "5m",
+// "10m", "15m", "30m" or "1h").
+#[derive(Deserialize, Debug)]
+pub struct UptimeAlert {
+    pub id: String,
+    pub name: String,
+    #[serde(rename="type")]
+    pub alert_type: String,
+    pub threshold: Option<f64>,
+    pub comparison: Option<String>,
+    pub notifications: AlertTargets,
+    pub period: Option<String>,
+}
+
+impl response::NotArray for UptimeAlert {}
+
+impl fmt::Display for UptimeAlert {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "ID: {}\n\
+                Name: {}\n\
+                Type: {}\n\
+                Threshold: {}\n\
+                Comparison: {}\n\
+                Period: {}\n\
+                Emails:{}\n",
+               self.id,
+               self.name,
+               self.alert_type,
+               self.threshold.map(|t| format!("{}", t)).unwrap_or("None".to_owned()),
+               self.comparison.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.period.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.notifications
+                   .email
+                   .iter()
+                   .fold(String::new(), |acc, s| acc + &format!(" {},", s)[..]))
+    }
+}
+
+pub type UptimeAlerts = Vec<UptimeAlert>;
+
+impl NamedResponse for UptimeAlert {
+    fn name<'a>() -> Cow<'a, str> { "alert".into() }
+}
@@ -0,0 +1,31 @@
+use response::{Domain, Droplet, FloatingIp, LoadBalancer, Volume, Vpc};
+
+/// A resource which can be referred to by a uniform resource name (i.e. `do:droplet:4126873`),
+/// which is how resources are assigned to projects
+pub trait Urn {
+    fn urn(&self) -> String;
+}
+
+impl Urn for Droplet {
+    fn urn(&self) -> String { format!("do:droplet:{:.0}", self.id) }
+}
+
+impl Urn for Domain {
+    fn urn(&self) -> String { format!("do:domain:{}", self.name) }
+}
+
+impl Urn for FloatingIp {
+    fn urn(&self) -> String { format!("do:floatingip:{}", self.ip) }
+}
+
+impl Urn for LoadBalancer {
+    fn urn(&self) -> String { format!("do:loadbalancer:{}", self.id) }
+}
+
+impl Urn for Volume {
+    fn urn(&self) -> String { format!("do:volume:{}", self.id) }
+}
+
+impl Urn for Vpc {
+    fn urn(&self) -> String { self.urn.clone() }
+}
@@ -0,0 +1,133 @@
+// id               string      The unique identifier for the Block Storage volume.
+// region           object      The region that the Block Storage volume is located in. When
+// setting a region, the value should be the slug identifier for the region. When you query a
+// Block Storage volume, the entire region object will be returned.
+// droplet_ids      array       An array containing the IDs of the Droplets the volume is
+// attached to. Note that at this time, a volume can only be attached to a single Droplet.
+// name             string      A human-readable name for the Block Storage volume. Must be
+// lowercase and be composed only of numbers, letters and "-", up to a limit of 64 characters.
+// description      string      An optional free-form text field to describe a Block Storage
+// volume.
+// size_gigabytes   number      The size of the Block Storage volume in GiB (1024^3).
+// created_at       string      A time value given in ISO8601 combined date and time format
+// that represents when the Block Storage volume was created.
+// filesystem_type  string      The type of filesystem currently in-use on the volume (i.e.
+// "ext4", "xfs" or "" if unformatted).
+// filesystem_label string      The label currently applied to the filesystem.
+
+use std::fmt;
+use std::borrow::Cow;
+
+use response::{NamedResponse, Region};
+use response;
+
+#[derive(Deserialize, Debug)]
+pub struct Volume {
+    pub id: String,
+    pub region: Region,
+    pub droplet_ids: Vec<f64>,
+    pub name: String,
+    pub description: Option<String>,
+    pub size_gigabytes: f64,
+    pub created_at: String,
+    pub filesystem_type: Option<String>,
+    pub filesystem_label: Option<String>,
+}
+
+impl response::NotArray for Volume {}
+
+impl fmt::Display for Volume {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "ID: {}\n\
+                Name: {}\n\
+                Description: {}\n\
+                Region: {}\n\
+                Size: {:.0} GB\n\
+                Droplet IDs: {}\n\
+                Filesystem Type: {}\n\
+                Filesystem Label: {}\n\
+                Created At: {}\n",
+               self.id,
+               self.name,
+               if let Some(ref d) = self.description {
+                   &d[..]
+               } else {
+                   "None"
+               },
+               self.region.slug,
+               self.size_gigabytes,
+               self.droplet_ids
+                   .iter()
+                   .fold(String::new(), |acc, id| acc + &format!(" {:.0},", id)[..]),
+               if let Some(ref t) = self.filesystem_type {
+                   &t[..]
+               } else {
+                   "None"
+               },
+               if let Some(ref l) = self.filesystem_label {
+                   &l[..]
+               } else {
+                   "None"
+               },
+               self.created_at)
+    }
+}
+
+pub type Volumes = Vec<Volume>;
+
+impl NamedResponse for Volume {
+    fn name<'a>() -> Cow<'a, str> { "volume".into() }
+}
+
+// id               string      The unique identifier for the snapshot.
+// name             string      A human-readable name for the snapshot.
+// created_at       string      A time value given in ISO8601 combined date and time format
+// that represents when the snapshot was created.
+// regions          array       An array of the regions that the snapshot is available in.
+// resource_id      string      The unique identifier for the resource the snapshot
+// originated from.
+// resource_type    string      The type of resource the snapshot originated from ("volume").
+// min_disk_size    number      The minimum size in GB required for a volume to be created
+// from this snapshot.
+// size_gigabytes   number      The billable size of the snapshot in gigabytes.
+
+#[derive(Deserialize, Debug)]
+pub struct VolumeSnapshot {
+    pub id: String,
+    pub name: String,
+    pub created_at: String,
+    pub regions: Vec<String>,
+    pub resource_id: String,
+    pub resource_type: String,
+    pub min_disk_size: f64,
+    pub size_gigabytes: f64,
+}
+
+impl response::NotArray for VolumeSnapshot {}
+
+impl fmt::Display for VolumeSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "ID: {}\n\
+                Name: {}\n\
+                Volume ID: {}\n\
+                Regions:{}\n\
+                Minimum Disk Size: {:.0} GB\n\
+                Size: {} GB\n\
+                Created At: {}\n",
+               self.id,
+               self.name,
+               self.resource_id,
+               self.regions.iter().fold(String::new(), |acc, s| acc + &format!(" {},", s)[..]),
+               self.min_disk_size,
+               self.size_gigabytes,
+               self.created_at)
+    }
+}
+
+pub type VolumeSnapshots = Vec<VolumeSnapshot>;
+
+impl NamedResponse for VolumeSnapshot {
+    fn name<'a>() -> Cow<'a, str> { "snapshot".into() }
+}
@@ -0,0 +1,91 @@
+// id               string      A unique ID that can be used to identify and reference the VPC.
+// urn              string      The uniform resource name (URN) for the VPC.
+// name             string      The name of the VPC. Must be unique and may only contain
+// alphanumeric characters, dashes, and periods.
+// description      string      A free-form text field for describing the VPC.
+// region           string      The slug identifier for the region where the VPC is located.
+// ip_range         string      The range of IP addresses in the VPC in CIDR notation.
+// default          boolean     A boolean value indicating whether or not the VPC is the default
+// network for the region.
+// created_at       string      A time value given in ISO8601 combined date and time format that
+// represents when the VPC was created.
+
+use std::fmt;
+use std::borrow::Cow;
+
+use response::NamedResponse;
+use response;
+
+#[derive(Deserialize, Debug)]
+pub struct Vpc {
+    pub id: String,
+    pub urn: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub region: String,
+    pub ip_range: String,
+    pub default: bool,
+    pub created_at: String,
+}
+
+impl response::NotArray for Vpc {}
+
+impl fmt::Display for Vpc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "ID: {}\n\
+                URN: {}\n\
+                Name: {}\n\
+                Description: {}\n\
+                Region: {}\n\
+                IP Range: {}\n\
+                Default: {}\n\
+                Created At: {}\n",
+               self.id,
+               self.urn,
+               self.name,
+               self.description.as_ref().map(|s| &s[..]).unwrap_or("None"),
+               self.region,
+               self.ip_range,
+               self.default,
+               self.created_at)
+    }
+}
+
+pub type Vpcs = Vec<Vpc>;
+
+impl NamedResponse for Vpc {
+    fn name<'a>() -> Cow<'a, str> { "vpc".into() }
+}
+
+// urn              string      The uniform resource name (URN) of the resource in the VPC.
+// name             string      The name of the resource.
+// created_at       string      A time value given in ISO8601 combined date and time format that
+// represents when the resource was created.
+
+#[derive(Deserialize, Debug)]
+pub struct VpcMember {
+    pub urn: String,
+    pub name: String,
+    pub created_at: String,
+}
+
+impl response::NotArray for VpcMember {}
+
+impl fmt::Display for VpcMember {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "URN: {}\n\
+                Name: {}\n\
+                Created At: {}\n",
+               self.urn,
+               self.name,
+               self.created_at)
+    }
+}
+
+pub type VpcMembers = Vec<VpcMember>;
+
+impl NamedResponse for VpcMember {
+    fn name<'a>() -> Cow<'a, str> { "member".into() }
+}
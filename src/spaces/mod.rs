@@ -0,0 +1,575 @@
+//! A client for Spaces, DigitalOcean's S3-compatible object storage.
+//!
+//! Spaces isn't part of the DigitalOcean API: buckets and objects are managed through the S3
+//! protocol, authenticated with a separate pair of Spaces access keys rather than the API token.
+//! Requests are signed with AWS Signature Version 4 and use path-style addressing
+//! (`https://$REGION.digitaloceanspaces.com/$BUCKET/$KEY`), so any S3-compatible endpoint (i.e.
+//! a local MinIO, or `doapi::testing::MockSpaces`) can be used instead through
+//! `SpacesBuilder::endpoint`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # extern crate hyper;
+//! # extern crate doapi;
+//! # use std::fs::File;
+//! # use std::time::Duration;
+//! # use hyper::method::Method;
+//! # fn main() {
+//! use doapi::spaces::{Acl, Spaces, MIN_PART_SIZE};
+//!
+//! let spaces = Spaces::new("DO00EXAMPLE", "secret", "nyc3");
+//! spaces.create_bucket("backups").unwrap();
+//! spaces.put_object("backups", "notes/today.txt", b"hello", "text/plain", Acl::Private).unwrap();
+//! let file = File::open("db.tar.gz").unwrap();
+//! spaces.upload("backups", "db.tar.gz", file, "application/gzip", Acl::Private, MIN_PART_SIZE)
+//!       .unwrap();
+//! for object in spaces.list_objects("backups", Some("notes/"), None).unwrap().objects {
+//!     println!("{} ({} bytes)", object.key, object.size);
+//! }
+//! let url = spaces.presigned_url(Method::Get, "backups", "db.tar.gz", Duration::from_secs(3600))
+//!                 .unwrap();
+//! println!("Download for the next hour: {}", url);
+//! # }
+//! ```
+
+use std::fmt;
+use std::io::Read;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hyper::method::Method;
+use url::Url;
+
+use response::DoError;
+use transport::{HttpResponse, HyperTransport, Transport};
+use Error;
+
+use self::sigv4::Signer;
+
+mod sha256;
+#[doc(hidden)]
+pub mod sigv4;
+#[doc(hidden)]
+pub mod xml;
+
+/// The smallest part size S3 accepts for every part but the last of a multipart upload (5 MiB)
+pub const MIN_PART_SIZE: usize = 5 * 1024 * 1024;
+
+/// The longest a presigned URL may be valid for (7 days)
+pub const MAX_PRESIGN_EXPIRY: u64 = 604800;
+
+/// A canned access control list, applied to objects through the `x-amz-acl` header. Bucket ACLs
+/// aren't supported.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Acl {
+    /// Only the owner has access (the default)
+    Private,
+    /// Anyone may read, only the owner may write
+    PublicRead,
+}
+
+impl Acl {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Acl::Private => "private",
+            Acl::PublicRead => "public-read",
+        }
+    }
+}
+
+impl fmt::Display for Acl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.as_str()) }
+}
+
+/// A bucket, as returned by `Spaces::list_buckets`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bucket {
+    pub name: String,
+    /// A time value given in ISO8601 combined date and time format
+    pub creation_date: String,
+}
+
+/// An object, as returned by `Spaces::list_objects`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Object {
+    pub key: String,
+    /// A time value given in ISO8601 combined date and time format
+    pub last_modified: String,
+    /// The entity tag of the object, including its quotes
+    pub etag: String,
+    pub size: u64,
+}
+
+/// The objects of a bucket under a prefix, see `Spaces::list_objects`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObjectList {
+    pub objects: Vec<Object>,
+    /// The distinct key prefixes up to the next delimiter (i.e. the "directories"), when a
+    /// delimiter was given
+    pub common_prefixes: Vec<String>,
+}
+
+/// Configures and builds a `Spaces` client, see `Spaces::builder()`
+pub struct SpacesBuilder {
+    access_key: String,
+    secret_key: String,
+    region: String,
+    endpoint: Option<String>,
+    transport: Arc<Transport>,
+}
+
+impl SpacesBuilder {
+    /// Sets the Spaces access key pair requests are signed with
+    pub fn keys(mut self, access_key: &str, secret_key: &str) -> SpacesBuilder {
+        self.access_key = access_key.to_owned();
+        self.secret_key = secret_key.to_owned();
+        self
+    }
+
+    /// Sets the region of the Spaces (i.e. `"nyc3"` or `"ams3"`), which is also the region
+    /// requests are signed for
+    pub fn region(mut self, region: &str) -> SpacesBuilder {
+        self.region = region.to_owned();
+        self
+    }
+
+    /// Sends all requests to `url` instead of `https://$REGION.digitaloceanspaces.com`, for
+    /// instance to a local S3-compatible stand-in
+    pub fn endpoint<S>(mut self, url: S) -> SpacesBuilder
+        where S: Into<String>
+    {
+        self.endpoint = Some(url.into());
+        self
+    }
+
+    /// Sends all requests through `transport` instead of the default `HyperTransport`
+    pub fn transport<T>(mut self, transport: T) -> SpacesBuilder
+        where T: Transport + 'static
+    {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Builds the client, failing if the endpoint is invalid
+    pub fn build(self) -> Result<Spaces, Error> {
+        let endpoint = self.endpoint
+                           .unwrap_or(format!("https://{}.digitaloceanspaces.com", self.region));
+        let endpoint = try!(Url::parse(&endpoint[..]));
+        if endpoint.host_str().is_none() {
+            return Err(Error::Url(::url::ParseError::EmptyHost));
+        }
+        Ok(Spaces {
+            access_key: self.access_key,
+            secret_key: self.secret_key,
+            region: self.region,
+            endpoint: endpoint,
+            transport: self.transport,
+        })
+    }
+}
+
+/// A client for the buckets and objects of one Spaces region
+#[derive(Clone)]
+pub struct Spaces {
+    access_key: String,
+    secret_key: String,
+    region: String,
+    endpoint: Url,
+    transport: Arc<Transport>,
+}
+
+impl Spaces {
+    /// Creates a client for `region` signing requests with the given access key pair
+    pub fn new(access_key: &str, secret_key: &str, region: &str) -> Spaces {
+        Spaces::builder()
+            .keys(access_key, secret_key)
+            .region(region)
+            .build()
+            .expect("the default endpoint is valid")
+    }
+
+    /// Returns a `SpacesBuilder` which can be used to configure the keys, region, endpoint and
+    /// `Transport` of a new client
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use doapi::spaces::Spaces;
+    /// let spaces = Spaces::builder()
+    ///                  .keys("minioadmin", "minioadmin")
+    ///                  .region("us-east-1")
+    ///                  .endpoint("http://127.0.0.1:9000")
+    ///                  .build()
+    ///                  .unwrap();
+    /// ```
+    pub fn builder() -> SpacesBuilder {
+        SpacesBuilder {
+            access_key: String::new(),
+            secret_key: String::new(),
+            region: "nyc3".to_owned(),
+            endpoint: None,
+            transport: Arc::new(HyperTransport),
+        }
+    }
+
+    /// Lists the buckets owned by the access key's account
+    pub fn list_buckets(&self) -> Result<Vec<Bucket>, Error> {
+        // GET: "https://$REGION.digitaloceanspaces.com/"
+        let resp = try!(self.send(Method::Get, None, None, &[], &[], None));
+        let body = resp.body_string();
+        Ok(xml::elements(&body[..], "Bucket")
+               .into_iter()
+               .map(|b| {
+                   Bucket {
+                       name: xml::text(b, "Name").unwrap_or(String::new()),
+                       creation_date: xml::text(b, "CreationDate").unwrap_or(String::new()),
+                   }
+               })
+               .collect())
+    }
+
+    pub fn create_bucket(&self, bucket: &str) -> Result<(), Error> {
+        // PUT: "https://$REGION.digitaloceanspaces.com/$BUCKET"
+        self.send(Method::Put, Some(bucket), None, &[], &[], None).map(|_| ())
+    }
+
+    /// Deletes `bucket`, which must be empty
+    pub fn delete_bucket(&self, bucket: &str) -> Result<(), Error> {
+        // DELETE: "https://$REGION.digitaloceanspaces.com/$BUCKET"
+        self.send(Method::Delete, Some(bucket), None, &[], &[], None).map(|_| ())
+    }
+
+    /// Uploads `data` in a single request, returning the entity tag of the new object. Use
+    /// `upload` for large files.
+    pub fn put_object(&self,
+                      bucket: &str,
+                      key: &str,
+                      data: &[u8],
+                      content_type: &str,
+                      acl: Acl)
+                      -> Result<String, Error> {
+        // PUT: "https://$REGION.digitaloceanspaces.com/$BUCKET/$KEY"
+        let headers = object_headers(content_type, acl);
+        let resp = try!(self.send(Method::Put, Some(bucket), Some(key), &[], &headers, Some(data)));
+        etag(&resp)
+    }
+
+    /// Downloads the contents of an object
+    pub fn get_object(&self, bucket: &str, key: &str) -> Result<Vec<u8>, Error> {
+        // GET: "https://$REGION.digitaloceanspaces.com/$BUCKET/$KEY"
+        self.send(Method::Get, Some(bucket), Some(key), &[], &[], None).map(|r| r.body)
+    }
+
+    pub fn delete_object(&self, bucket: &str, key: &str) -> Result<(), Error> {
+        // DELETE: "https://$REGION.digitaloceanspaces.com/$BUCKET/$KEY"
+        self.send(Method::Delete, Some(bucket), Some(key), &[], &[], None).map(|_| ())
+    }
+
+    /// Lists the objects of `bucket` whose keys start with `prefix`, following continuation
+    /// tokens until every object has been listed. With a `delimiter` (i.e. `"/"`), keys
+    /// containing the delimiter after the prefix are rolled up into `common_prefixes` instead.
+    pub fn list_objects(&self,
+                        bucket: &str,
+                        prefix: Option<&str>,
+                        delimiter: Option<&str>)
+                        -> Result<ObjectList, Error> {
+        // GET: "https://$REGION.digitaloceanspaces.com/$BUCKET?list-type=2&prefix=$PREFIX&
+        //       delimiter=$DELIMITER&continuation-token=$TOKEN"
+        let mut list = ObjectList {
+            objects: vec![],
+            common_prefixes: vec![],
+        };
+        let mut token: Option<String> = None;
+        loop {
+            let mut query = vec![("list-type".to_owned(), "2".to_owned())];
+            if let Some(p) = prefix {
+                query.push(("prefix".to_owned(), p.to_owned()));
+            }
+            if let Some(d) = delimiter {
+                query.push(("delimiter".to_owned(), d.to_owned()));
+            }
+            if let Some(t) = token.take() {
+                query.push(("continuation-token".to_owned(), t));
+            }
+            let resp = try!(self.send(Method::Get, Some(bucket), None, &query, &[], None));
+            let body = resp.body_string();
+            for c in xml::elements(&body[..], "Contents") {
+                list.objects.push(Object {
+                    key: xml::text(c, "Key").unwrap_or(String::new()),
+                    last_modified: xml::text(c, "LastModified").unwrap_or(String::new()),
+                    etag: xml::text(c, "ETag").unwrap_or(String::new()),
+                    size: xml::text(c, "Size").and_then(|s| s.parse().ok()).unwrap_or(0),
+                });
+            }
+            for p in xml::elements(&body[..], "CommonPrefixes") {
+                if let Some(p) = xml::text(p, "Prefix") {
+                    list.common_prefixes.push(p);
+                }
+            }
+            let truncated = xml::text(&body[..], "IsTruncated").map(|t| t == "true");
+            token = xml::text(&body[..], "NextContinuationToken");
+            if truncated != Some(true) || token.is_none() {
+                return Ok(list);
+            }
+        }
+    }
+
+    /// Uploads everything read from `reader` as a multipart upload of `part_size` byte parts,
+    /// returning the entity tag of the new object. Only one part is held in memory at a time.
+    /// Should any part fail, the upload is aborted so no parts are left behind.
+    pub fn upload<R>(&self,
+                     bucket: &str,
+                     key: &str,
+                     mut reader: R,
+                     content_type: &str,
+                     acl: Acl,
+                     part_size: usize)
+                     -> Result<String, Error>
+        where R: Read
+    {
+        if part_size < MIN_PART_SIZE {
+            return Err(Error::Invalid(format!("part size must be at least {} bytes, not {}",
+                                              MIN_PART_SIZE,
+                                              part_size)));
+        }
+        // POST: "https://$REGION.digitaloceanspaces.com/$BUCKET/$KEY?uploads"
+        let headers = object_headers(content_type, acl);
+        let query = [("uploads".to_owned(), String::new())];
+        let resp = try!(self.send(Method::Post, Some(bucket), Some(key), &query, &headers, None));
+        let upload_id = match xml::text(&resp.body_string()[..], "UploadId") {
+            Some(id) => id,
+            None => return Err(Error::Header("UploadId missing from response".to_owned())),
+        };
+        let result = self.upload_parts(bucket, key, &upload_id[..], &mut reader, part_size);
+        if result.is_err() {
+            // DELETE: "https://$REGION.digitaloceanspaces.com/$BUCKET/$KEY?uploadId=$ID"
+            debug!("Aborting multipart upload {}", &upload_id[..]);
+            let query = [("uploadId".to_owned(), upload_id.clone())];
+            let _ = self.send(Method::Delete, Some(bucket), Some(key), &query, &[], None);
+        }
+        result
+    }
+
+    fn upload_parts(&self,
+                    bucket: &str,
+                    key: &str,
+                    upload_id: &str,
+                    reader: &mut Read,
+                    part_size: usize)
+                    -> Result<String, Error> {
+        let mut parts: Vec<(u32, String)> = vec![];
+        let mut buf = vec![0u8; part_size];
+        loop {
+            let n = try!(read_full(reader, &mut buf[..]));
+            // An empty input is still uploaded as one (empty) part
+            if n == 0 && !parts.is_empty() {
+                break;
+            }
+            // PUT: "https://$REGION.digitaloceanspaces.com/$BUCKET/$KEY?partNumber=$N&
+            //       uploadId=$ID"
+            let number = parts.len() as u32 + 1;
+            let query = [("partNumber".to_owned(), number.to_string()),
+                         ("uploadId".to_owned(), upload_id.to_owned())];
+            let resp = try!(self.send(Method::Put,
+                                      Some(bucket),
+                                      Some(key),
+                                      &query,
+                                      &[],
+                                      Some(&buf[..n])));
+            parts.push((number, try!(etag(&resp))));
+            if n < part_size {
+                break;
+            }
+        }
+        // POST: "https://$REGION.digitaloceanspaces.com/$BUCKET/$KEY?uploadId=$ID"
+        // body:
+        //      <CompleteMultipartUpload><Part><PartNumber>1</PartNumber><ETag>"..."</ETag>
+        //      </Part>...</CompleteMultipartUpload>
+        let body = parts.iter().fold(String::from("<CompleteMultipartUpload>"), |acc, p| {
+            acc +
+            &format!("<Part><PartNumber>{}</PartNumber><ETag>{}</ETag></Part>",
+                     p.0,
+                     xml::escape(&p.1[..]))[..]
+        }) + "</CompleteMultipartUpload>";
+        let query = [("uploadId".to_owned(), upload_id.to_owned())];
+        let resp = try!(self.send(Method::Post,
+                                  Some(bucket),
+                                  Some(key),
+                                  &query,
+                                  &[],
+                                  Some(body.as_bytes())));
+        // Completing can fail after the 200 status has been sent, with an error as the body
+        let body = resp.body_string();
+        if !xml::elements(&body[..], "Error").is_empty() {
+            return Err(s3_error(resp.status, &body[..]));
+        }
+        Ok(xml::text(&body[..], "ETag").unwrap_or(String::new()))
+    }
+
+    /// Returns a URL which allows anyone holding it to make a `method` request for the object
+    /// until `expires` has passed (at most 7 days), without any credentials
+    pub fn presigned_url(&self,
+                         method: Method,
+                         bucket: &str,
+                         key: &str,
+                         expires: Duration)
+                         -> Result<String, Error> {
+        let secs = expires.as_secs();
+        if secs == 0 || secs > MAX_PRESIGN_EXPIRY {
+            return Err(Error::Invalid(format!("presigned URLs expire after 1 to {} seconds, \
+                                               not {}",
+                                              MAX_PRESIGN_EXPIRY,
+                                              secs)));
+        }
+        let path = self.path(Some(bucket), Some(key));
+        let pairs = self.signer().presign(&method.to_string()[..],
+                                          &self.host()[..],
+                                          &path[..],
+                                          &[],
+                                          secs,
+                                          now());
+        Ok(self.url(&path[..], &pairs[..]))
+    }
+
+    /// Sets the canned ACL of an object
+    pub fn set_acl(&self, bucket: &str, key: &str, acl: Acl) -> Result<(), Error> {
+        // PUT: "https://$REGION.digitaloceanspaces.com/$BUCKET/$KEY?acl"
+        let query = [("acl".to_owned(), String::new())];
+        let headers = [("x-amz-acl".to_owned(), acl.as_str().to_owned())];
+        self.send(Method::Put, Some(bucket), Some(key), &query, &headers, None).map(|_| ())
+    }
+
+    /// Returns the canned ACL matching the grants of an object, which is `Acl::PublicRead` if
+    /// everyone may read it
+    pub fn get_acl(&self, bucket: &str, key: &str) -> Result<Acl, Error> {
+        // GET: "https://$REGION.digitaloceanspaces.com/$BUCKET/$KEY?acl"
+        let query = [("acl".to_owned(), String::new())];
+        let resp = try!(self.send(Method::Get, Some(bucket), Some(key), &query, &[], None));
+        let body = resp.body_string();
+        let public = xml::elements(&body[..], "Grant").into_iter().any(|g| {
+            let everyone = xml::text(g, "URI").map(|u| u.ends_with("/AllUsers")).unwrap_or(false);
+            let perm = xml::text(g, "Permission").unwrap_or(String::new());
+            everyone && (perm == "READ" || perm == "FULL_CONTROL")
+        });
+        Ok(if public {
+            Acl::PublicRead
+        } else {
+            Acl::Private
+        })
+    }
+
+    fn signer(&self) -> Signer {
+        Signer {
+            access_key: &self.access_key[..],
+            secret_key: &self.secret_key[..],
+            region: &self.region[..],
+        }
+    }
+
+    // The host (and port, unless it's the default) requests are signed for
+    fn host(&self) -> String {
+        let host = self.endpoint.host_str().unwrap_or("");
+        match self.endpoint.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host.to_owned(),
+        }
+    }
+
+    // The URI encoded path of a bucket or object, below any path of the endpoint
+    fn path(&self, bucket: Option<&str>, key: Option<&str>) -> String {
+        let mut path = self.endpoint.path().trim_right_matches('/').to_owned();
+        path.push('/');
+        if let Some(bucket) = bucket {
+            path.push_str(&sigv4::uri_encode(bucket, false)[..]);
+            if let Some(key) = key {
+                path.push('/');
+                path.push_str(&sigv4::uri_encode(key, true)[..]);
+            }
+        }
+        path
+    }
+
+    fn url(&self, path: &str, query: &[(String, String)]) -> String {
+        let mut url = format!("{}://{}{}", self.endpoint.scheme(), self.host(), path);
+        if !query.is_empty() {
+            url.push('?');
+            url.push_str(&sigv4::canonical_query(query)[..]);
+        }
+        url
+    }
+
+    fn send(&self,
+            method: Method,
+            bucket: Option<&str>,
+            key: Option<&str>,
+            query: &[(String, String)],
+            headers: &[(String, String)],
+            body: Option<&[u8]>)
+            -> Result<HttpResponse, Error> {
+        let time = now();
+        let path = self.path(bucket, key);
+        let hash = sigv4::payload_hash(body.unwrap_or(&[]));
+        let mut headers = headers.to_vec();
+        headers.push(("Host".to_owned(), self.host()));
+        headers.push(("x-amz-content-sha256".to_owned(), hash.clone()));
+        headers.push(("x-amz-date".to_owned(), sigv4::amz_date(time)));
+        let auth = self.signer().authorization(&method.to_string()[..],
+                                               &path[..],
+                                               query,
+                                               &headers[..],
+                                               &hash[..],
+                                               time);
+        headers.push(("Authorization".to_owned(), auth));
+        // S3 rejects chunked uploads without a signature per chunk
+        headers.push(("Content-Length".to_owned(), body.map(|b| b.len()).unwrap_or(0).to_string()));
+        let url = self.url(&path[..], query);
+        let resp = try!(self.transport.send(method, &url[..], &headers[..], body));
+        if !resp.is_success() {
+            debug!("Error response: {} {}", resp.status, &resp.body_string()[..]);
+            return Err(s3_error(resp.status, &resp.body_string()[..]));
+        }
+        Ok(resp)
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+fn object_headers(content_type: &str, acl: Acl) -> Vec<(String, String)> {
+    vec![("Content-Type".to_owned(), content_type.to_owned()),
+         ("x-amz-acl".to_owned(), acl.as_str().to_owned())]
+}
+
+fn etag(resp: &HttpResponse) -> Result<String, Error> {
+    match resp.header("etag") {
+        Some(etag) => Ok(etag.to_owned()),
+        None => Err(Error::Header("ETag missing from response".to_owned())),
+    }
+}
+
+// Reads until `buf` is full or the reader is exhausted, returning the number of bytes read
+fn read_full(reader: &mut Read, buf: &mut [u8]) -> Result<usize, Error> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match try!(reader.read(&mut buf[filled..])) {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    Ok(filled)
+}
+
+// Turns an S3 error document (`<Error><Code>NoSuchKey</Code><Message>...</Message>...`) into
+// an `Error::Api`, with the code as the error ID
+fn s3_error(status: u16, body: &str) -> Error {
+    Error::Api {
+        status: status,
+        error: DoError {
+            id: xml::text(body, "Code").unwrap_or(status.to_string()),
+            message: xml::text(body, "Message").unwrap_or(body.to_owned()),
+            request_id: xml::text(body, "RequestId"),
+        },
+    }
+}
@@ -0,0 +1,119 @@
+//! Just enough XML for the documents S3 returns: elements are found by tag name, attributes are
+//! skipped and entities in text are decoded. Namespaces, CDATA, comments and elements nested
+//! within an element of the same tag aren't supported.
+
+/// Returns the contents of every `<tag>` element in `xml`. Each element ends at the first
+/// `</tag>` after it, so `tag` may not nest within itself.
+pub fn elements<'a>(xml: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut found = vec![];
+    let mut rest = xml;
+    while let Some(start) = rest.find(&open[..]) {
+        let after = &rest[start + open.len()..];
+        // Skip longer tag names sharing the prefix (i.e. `<Prefix` when looking for `<Pre`)
+        match after.chars().next() {
+            Some('>') | Some(' ') | Some('\t') | Some('\r') | Some('\n') | Some('/') => {}
+            _ => {
+                rest = after;
+                continue;
+            }
+        }
+        let gt = match after.find('>') {
+            Some(i) => i,
+            None => break,
+        };
+        if after[..gt].ends_with('/') {
+            // `<tag/>` is an empty element
+            found.push("");
+            rest = &after[gt + 1..];
+            continue;
+        }
+        let content = &after[gt + 1..];
+        match content.find(&close[..]) {
+            Some(end) => {
+                found.push(&content[..end]);
+                rest = &content[end + close.len()..];
+            }
+            None => break,
+        }
+    }
+    found
+}
+
+/// Returns the decoded text of the first `<tag>` element in `xml`
+pub fn text(xml: &str, tag: &str) -> Option<String> {
+    elements(xml, tag).into_iter().next().map(unescape)
+}
+
+/// Decodes the predefined entities and numeric character references in `s`
+pub fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        let after = &rest[amp..];
+        let semi = match after.find(';') {
+            Some(i) => i,
+            None => {
+                out.push_str(after);
+                rest = "";
+                break;
+            }
+        };
+        let decoded = match &after[1..semi] {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            e if e.starts_with("#x") => {
+                u32::from_str_radix(&e[2..], 16).ok().and_then(::std::char::from_u32)
+            }
+            e if e.starts_with('#') => e[1..].parse::<u32>().ok().and_then(::std::char::from_u32),
+            _ => None,
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &after[semi + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &after[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Escapes `s` for use as element text
+pub fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{elements, escape, text, unescape};
+
+    #[test]
+    fn finds_elements() {
+        let xml = concat!(r#"<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">"#,
+                          "<Prefix>photos/</Prefix><Contents><Key>a&amp;b.jpg</Key></Contents>",
+                          "<Contents><Key>c.jpg</Key></Contents><Marker/>",
+                          "</ListBucketResult>");
+        assert_eq!(elements(xml, "Contents").len(), 2);
+        assert_eq!(text(xml, "Key"), Some("a&b.jpg".to_owned()));
+        assert_eq!(text(xml, "Prefix"), Some("photos/".to_owned()));
+        assert_eq!(text(xml, "Pre"), None);
+        assert_eq!(text(xml, "Marker"), Some(String::new()));
+        assert_eq!(elements(xml, "ListBucketResult").len(), 1);
+    }
+
+    #[test]
+    fn entities() {
+        assert_eq!(unescape("&quot;abc&quot; &#65;&#x42; & more"), "\"abc\" AB & more");
+        assert_eq!(escape("<a & b>"), "&lt;a &amp; b&gt;");
+    }
+}
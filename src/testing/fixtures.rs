@@ -0,0 +1,554 @@
+// Builders for the JSON objects served by `MockServer`. The shapes mirror the examples given in
+// the DigitalOcean API v2 documentation, so they deserialize into the types found in
+// `doapi::response`
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use serde_json::Value;
+use serde_json::builder::{ArrayBuilder, ObjectBuilder};
+
+pub const CREATED_AT: &'static str = "2016-11-20T00:00:00Z";
+
+pub fn region(slug: &str) -> Value {
+    ObjectBuilder::new()
+        .insert("name", region_name(slug))
+        .insert("slug", slug)
+        .insert_array("sizes", |a| {
+            a.push("512mb")
+             .push("1gb")
+             .push("2gb")
+             .push("4gb")
+        })
+        .insert_array("features", |a| {
+            a.push("private_networking")
+             .push("backups")
+             .push("ipv6")
+             .push("metadata")
+        })
+        .insert("available", true)
+        .build()
+}
+
+fn region_name(slug: &str) -> String {
+    match slug {
+        "nyc1" => "New York 1".to_owned(),
+        "nyc2" => "New York 2".to_owned(),
+        "nyc3" => "New York 3".to_owned(),
+        "sfo1" => "San Francisco 1".to_owned(),
+        "ams2" => "Amsterdam 2".to_owned(),
+        "lon1" => "London 1".to_owned(),
+        "fra1" => "Frankfurt 1".to_owned(),
+        _ => slug.to_uppercase(),
+    }
+}
+
+pub fn size(slug: &str) -> Value {
+    let (memory, vcpus, disk, transfer, monthly) = match slug {
+        "1gb" => (1024, 1, 30, 2.0, 10.0),
+        "2gb" => (2048, 2, 40, 3.0, 20.0),
+        "4gb" => (4096, 2, 60, 4.0, 40.0),
+        _ => (512, 1, 20, 1.0, 5.0),
+    };
+    ObjectBuilder::new()
+        .insert("slug", slug)
+        .insert("memory", memory)
+        .insert("vcpus", vcpus)
+        .insert("disk", disk)
+        .insert("transfer", transfer)
+        .insert("price_monthly", monthly)
+        .insert("price_hourly", monthly / 672.0)
+        .insert_array("regions", |a| {
+            a.push("nyc1")
+             .push("nyc3")
+             .push("sfo1")
+             .push("ams2")
+        })
+        .insert("available", true)
+        .build()
+}
+
+pub fn image(id: u64,
+             name: &str,
+             slug: Option<&str>,
+             public: bool,
+             image_type: &str,
+             region: &str)
+             -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("name", name)
+        .insert("distribution", "Ubuntu")
+        .insert("slug", slug)
+        .insert("public", public)
+        .insert_array("regions", |a| a.push(region))
+        .insert("created_at", CREATED_AT)
+        .insert("min_disk_size", 20)
+        .insert("type", image_type)
+        .build()
+}
+
+pub fn droplet(id: u64, name: &str, region_slug: &str, size_slug: &str, image: Value) -> Value {
+    let size = size(size_slug);
+    let memory = size.find("memory").cloned().unwrap_or(Value::U64(512));
+    let vcpus = size.find("vcpus").cloned().unwrap_or(Value::U64(1));
+    let disk = size.find("disk").cloned().unwrap_or(Value::U64(20));
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("name", name)
+        .insert("memory", memory)
+        .insert("vcpus", vcpus)
+        .insert("disk", disk)
+        .insert("locked", false)
+        .insert("status", "active")
+        .insert("kernel", Value::Null)
+        .insert("created_at", CREATED_AT)
+        .insert_array("features", |a| a.push("virtio"))
+        .insert_array("backup_ids", |a| a)
+        .insert("next_backup_window", Value::Null)
+        .insert_array("snapshot_ids", |a| a)
+        .insert("image", image)
+        .insert("region", region(region_slug))
+        .insert("size", size)
+        .insert("size_slug", size_slug)
+        .insert_object("networks", |o| {
+            o.insert_array("v4", |a| {
+                 a.push_object(|n| {
+                     n.insert("ip_address", format!("104.131.{}.{}", (id / 250) % 250, id % 250 + 2))
+                      .insert("netmask", "255.255.192.0")
+                      .insert("gateway", "104.131.0.1")
+                      .insert("type", "public")
+                 })
+             })
+             .insert_array("v6", |a| a)
+        })
+        .build()
+}
+
+pub fn action(id: u64,
+              status: &str,
+              action_type: &str,
+              resource_id: u64,
+              resource_type: &str,
+              region_slug: &str)
+              -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("status", status)
+        .insert("type", action_type)
+        .insert("started_at", CREATED_AT)
+        .insert("completed_at", Value::Null)
+        .insert("resource_id", resource_id)
+        .insert("resource_type", resource_type)
+        .insert("region", region(region_slug))
+        .insert("region_slug", region_slug)
+        .build()
+}
+
+pub fn domain(name: &str, ip: &str) -> Value {
+    ObjectBuilder::new()
+        .insert("name", name)
+        .insert("ttl", 1800)
+        .insert("zone_file",
+                format!("$ORIGIN {0}.\n\
+                         $TTL 1800\n\
+                         {0}. IN SOA ns1.digitalocean.com. hostmaster.{0}. 1415982609 10800 \
+                         3600 604800 1800\n\
+                         {0}. 1800 IN NS ns1.digitalocean.com.\n\
+                         {0}. 1800 IN NS ns2.digitalocean.com.\n\
+                         {0}. 1800 IN NS ns3.digitalocean.com.\n\
+                         {0}. 1800 IN A {1}\n",
+                        name,
+                        ip))
+        .build()
+}
+
+pub fn ssh_key(id: u64, name: &str, public_key: &str) -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("fingerprint", fingerprint(public_key))
+        .insert("public_key", public_key)
+        .insert("name", name)
+        .build()
+}
+
+// Not a real MD5 fingerprint, but stable and formatted like one
+pub fn fingerprint(public_key: &str) -> String {
+    let mut bytes = vec![];
+    for salt in 0..2u8 {
+        let mut h = DefaultHasher::new();
+        salt.hash(&mut h);
+        public_key.hash(&mut h);
+        let n = h.finish();
+        for i in 0..8 {
+            bytes.push(((n >> (i * 8)) & 0xff) as u8);
+        }
+    }
+    bytes.iter()
+         .map(|b| format!("{:02x}", b))
+         .collect::<Vec<_>>()
+         .join(":")
+}
+
+pub fn account() -> Value {
+    ObjectBuilder::new()
+        .insert("droplet_limit", 25)
+        .insert("email", "sammy@digitalocean.com")
+        .insert("uuid", "b6fr89dbf6d9156cace5f3c78dc9851d957381ef")
+        .insert("email_verified", true)
+        .build()
+}
+
+pub fn error(id: &str, message: &str) -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("message", message)
+        .insert("request_id", "4d9d8375-3c56-4925-a3e7-eb137fed17e9")
+        .build()
+}
+
+pub fn array(items: Vec<Value>) -> Value {
+    items.into_iter().fold(ArrayBuilder::new(), |a, v| a.push(v)).build()
+}
+
+pub fn volume(id: &str, name: &str, region_slug: &str, size_gigabytes: u64) -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("region", region(region_slug))
+        .insert_array("droplet_ids", |a| a)
+        .insert("name", name)
+        .insert("description", "Block store for examples")
+        .insert("size_gigabytes", size_gigabytes)
+        .insert("created_at", CREATED_AT)
+        .insert("filesystem_type", "ext4")
+        .insert("filesystem_label", "example")
+        .build()
+}
+
+// `{"key": v}`, the shape of every single object response
+pub fn object(key: &str, v: Value) -> Value { ObjectBuilder::new().insert(key, v).build() }
+
+// A single page list response holding all of `items`
+pub fn list(key: &str, items: Vec<Value>) -> Value {
+    let total = items.len();
+    ObjectBuilder::new()
+        .insert(key, array(items))
+        .insert_object("links", |l| l)
+        .insert_object("meta", |m| m.insert("total", total))
+        .build()
+}
+
+pub fn floating_ip(ip: &str, region_slug: &str, droplet: Option<Value>) -> Value {
+    ObjectBuilder::new()
+        .insert("ip", ip)
+        .insert("region", region(region_slug))
+        .insert("droplet", droplet.unwrap_or(Value::Null))
+        .insert("locked", false)
+        .build()
+}
+
+pub fn load_balancer(id: &str, name: &str, region_slug: &str, droplet_ids: &[u64]) -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("name", name)
+        .insert("ip", "104.131.186.241")
+        .insert("algorithm", "round_robin")
+        .insert("status", "new")
+        .insert("created_at", CREATED_AT)
+        .insert_array("forwarding_rules", |a| {
+            a.push_object(|r| {
+                r.insert("entry_protocol", "http")
+                 .insert("entry_port", 80)
+                 .insert("target_protocol", "http")
+                 .insert("target_port", 80)
+                 .insert("certificate_id", "")
+                 .insert("tls_passthrough", false)
+            })
+        })
+        .insert_object("health_check", |h| {
+            h.insert("protocol", "http")
+             .insert("port", 80)
+             .insert("path", "/")
+             .insert("check_interval_seconds", 10)
+             .insert("response_timeout_seconds", 5)
+             .insert("healthy_threshold", 5)
+             .insert("unhealthy_threshold", 3)
+        })
+        .insert_object("sticky_sessions", |s| s.insert("type", "none"))
+        .insert("region", region(region_slug))
+        .insert("tag", "")
+        .insert("droplet_ids",
+                droplet_ids.iter().fold(ArrayBuilder::new(), |a, id| a.push(*id)).build())
+        .insert("redirect_http_to_https", false)
+        .build()
+}
+
+pub fn firewall(id: &str, name: &str) -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("name", name)
+        .insert("status", "waiting")
+        .insert("created_at", CREATED_AT)
+        .insert_array("pending_changes", |a| a)
+        .insert_array("inbound_rules", |a| {
+            a.push_object(|r| {
+                r.insert("protocol", "tcp")
+                 .insert("ports", "80")
+                 .insert_object("sources", |s| {
+                     s.insert_array("load_balancer_uids",
+                                    |a| a.push("4de7ac8b-495b-4884-9a69-1050c6793cd6"))
+                 })
+            })
+        })
+        .insert_array("outbound_rules", |a| {
+            a.push_object(|r| {
+                r.insert("protocol", "tcp")
+                 .insert("ports", "80")
+                 .insert_object("destinations", |d| {
+                     d.insert_array("addresses", |a| a.push("0.0.0.0/0").push("::/0"))
+                 })
+            })
+        })
+        .insert_array("droplet_ids", |a| a)
+        .insert_array("tags", |a| a.push("web"))
+        .build()
+}
+
+pub fn tag(name: &str, droplets: u64) -> Value {
+    ObjectBuilder::new()
+        .insert("name", name)
+        .insert_object("resources", |r| {
+            r.insert_object("droplets", |d| {
+                d.insert("count", droplets)
+                 .insert("last_tagged", Value::Null)
+            })
+        })
+        .build()
+}
+
+pub fn vpc(id: &str, name: &str, region_slug: &str, ip_range: &str) -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("urn", format!("do:vpc:{}", id))
+        .insert("name", name)
+        .insert("description", "")
+        .insert("region", region_slug)
+        .insert("ip_range", ip_range)
+        .insert("default", false)
+        .insert("created_at", CREATED_AT)
+        .build()
+}
+
+pub fn project(id: &str, name: &str, is_default: bool) -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("owner_uuid", "99525febec065ca37b2ffe4f852fd2b2581895e7")
+        .insert("owner_id", 258992)
+        .insert("name", name)
+        .insert("description", "")
+        .insert("purpose", "Web Application")
+        .insert("environment", "Production")
+        .insert("is_default", is_default)
+        .insert("created_at", CREATED_AT)
+        .insert("updated_at", CREATED_AT)
+        .build()
+}
+
+pub fn certificate(id: &str, name: &str, cert_type: &str, dns_names: &[&str]) -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("name", name)
+        .insert("not_after", "2017-02-22T00:23:00Z")
+        .insert("sha1_fingerprint", "dfcc9f57d86bf58e321c2c6c31c7a971be244ac7")
+        .insert("created_at", CREATED_AT)
+        .insert("dns_names",
+                array(dns_names.iter().map(|n| Value::String((*n).to_owned())).collect()))
+        .insert("state", "verified")
+        .insert("type", cert_type)
+        .build()
+}
+
+pub fn cdn_endpoint(id: &str, origin: &str, ttl: u64) -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("origin", origin)
+        .insert("endpoint", origin.replace(".digitaloceanspaces.", ".cdn.digitaloceanspaces."))
+        .insert("created_at", CREATED_AT)
+        .insert("ttl", ttl)
+        .insert("certificate_id", Value::Null)
+        .insert("custom_domain", Value::Null)
+        .build()
+}
+
+pub fn database(id: &str, name: &str, engine: &str, region_slug: &str) -> Value {
+    let host = format!("{}-do-user-1.b.db.ondigitalocean.com", name);
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("name", name)
+        .insert("engine", engine)
+        .insert("version", "11")
+        .insert_object("connection", |c| {
+            c.insert("uri",
+                     format!("postgresql://doadmin:wv78n3zpz42xezdk@{}:25060/defaultdb?\
+                              sslmode=require",
+                             host))
+             .insert("database", "defaultdb")
+             .insert("host", &host[..])
+             .insert("port", 25060)
+             .insert("user", "doadmin")
+             .insert("password", "wv78n3zpz42xezdk")
+             .insert("ssl", true)
+        })
+        .insert("num_nodes", 2)
+        .insert("size", "db-s-2vcpu-4gb")
+        .insert("region", region_slug)
+        .insert("status", "creating")
+        .insert("created_at", CREATED_AT)
+        .insert_array("tags", |a| a)
+        .build()
+}
+
+pub fn kubernetes_cluster(id: &str, name: &str, region_slug: &str) -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("name", name)
+        .insert("region", region_slug)
+        .insert("version", "1.18.8-do.0")
+        .insert("cluster_subnet", "10.244.0.0/16")
+        .insert("service_subnet", "10.245.0.0/16")
+        .insert("vpc_uuid", "c33931f2-a26a-4e61-b85c-4e95a2ec431b")
+        .insert("ipv4", "68.183.121.157")
+        .insert("endpoint", format!("https://{}.k8s.ondigitalocean.com", id))
+        .insert_array("tags", |a| a.push("k8s").push(format!("k8s:{}", id)))
+        .insert_array("node_pools", |a| {
+            a.push_object(|p| {
+                p.insert("id", "cdda885e-7663-40c8-bc74-3a036c66545d")
+                 .insert("name", "workers")
+                 .insert("size", "s-2vcpu-4gb")
+                 .insert("count", 1)
+                 .insert_array("tags", |a| a)
+                 .insert_array("nodes", |a| {
+                     a.push_object(|n| {
+                         n.insert("id", "478247f8-b1bb-4f7a-8db9-2a5f8d4b8f8f")
+                          .insert("name", "workers-ueh2")
+                          .insert_object("status", |s| s.insert("state", "running"))
+                          .insert("droplet_id", "205545370")
+                          .insert("created_at", CREATED_AT)
+                          .insert("updated_at", CREATED_AT)
+                     })
+                 })
+            })
+        })
+        .insert("auto_upgrade", false)
+        .insert_object("status", |s| s.insert("state", "provisioning"))
+        .insert("created_at", CREATED_AT)
+        .insert("updated_at", CREATED_AT)
+        .build()
+}
+
+pub fn alert_policy(uuid: &str, policy_type: &str, droplet_ids: &[u64]) -> Value {
+    ObjectBuilder::new()
+        .insert("uuid", uuid)
+        .insert("type", policy_type)
+        .insert("description", "High CPU")
+        .insert("compare", "GreaterThan")
+        .insert("value", 80.0)
+        .insert("window", "5m")
+        .insert_array("entities", |a| {
+            droplet_ids.iter().fold(a, |a, id| a.push(id.to_string()))
+        })
+        .insert_array("tags", |a| a)
+        .insert_object("alerts", |o| {
+            o.insert_array("email", |a| a.push("bob@example.com"))
+             .insert_array("slack", |a| {
+                 a.push_object(|s| {
+                     s.insert("channel", "#alerts")
+                      .insert("url", "https://hooks.slack.com/services/T1234567/AAAAAAAA")
+                 })
+             })
+        })
+        .insert("enabled", true)
+        .build()
+}
+
+pub fn deployment(id: &str, phase: &str) -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("phase", phase)
+        .insert_object("progress", |p| {
+            p.insert("success_steps", if phase == "ACTIVE" { 6 } else { 0 })
+             .insert("total_steps", 6)
+        })
+        .insert("cause", "manual")
+        .insert("created_at", CREATED_AT)
+        .insert("updated_at", CREATED_AT)
+        .build()
+}
+
+pub fn app(id: &str, name: &str, active_deployment: Option<Value>) -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("owner_uuid", "ff36cbc6fd350fe12577f5123133bb5ba01a2419")
+        .insert_object("spec", |s| {
+            s.insert("name", name)
+             .insert("region", "nyc")
+             .insert_array("services", |a| {
+                 a.push_object(|svc| {
+                     svc.insert("name", "api")
+                        .insert_object("github", |g| {
+                            g.insert("repo", "digitalocean/sample-golang")
+                             .insert("branch", "main")
+                        })
+                        .insert("instance_count", 1)
+                        .insert("http_port", 8080)
+                 })
+             })
+        })
+        .insert("live_url", format!("https://{}-abc12.ondigitalocean.app", name))
+        .insert("active_deployment", active_deployment.unwrap_or(Value::Null))
+        .insert("created_at", CREATED_AT)
+        .insert("updated_at", CREATED_AT)
+        .build()
+}
+
+pub fn uptime_check(id: &str, name: &str, target: &str, regions: &[&str]) -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("name", name)
+        .insert("type", "https")
+        .insert("target", target)
+        .insert_array("regions", |a| regions.iter().fold(a, |a, r| a.push(*r)))
+        .insert("enabled", true)
+        .build()
+}
+
+pub fn uptime_alert(id: &str, name: &str, alert_type: &str, threshold: u64) -> Value {
+    ObjectBuilder::new()
+        .insert("id", id)
+        .insert("name", name)
+        .insert("type", alert_type)
+        .insert("threshold", threshold)
+        .insert("comparison", "greater_than")
+        .insert_object("notifications", |o| {
+            o.insert_array("email", |a| a.push("bob@example.com"))
+             .insert_array("slack", |a| a)
+        })
+        .insert("period", "5m")
+        .build()
+}
+
+pub fn invoice_item(product: &str, description: &str, amount: &str) -> Value {
+    ObjectBuilder::new()
+        .insert("product", product)
+        .insert("resource_uuid", "711157cb-37c8-4817-b371-44fa3504a39c")
+        .insert("group_description", "")
+        .insert("description", description)
+        .insert("amount", amount)
+        .insert("duration", "744")
+        .insert("duration_unit", "Hours")
+        .insert("start_time", "2020-01-01T00:00:00Z")
+        .insert("end_time", "2020-02-01T00:00:00Z")
+        .insert("project_name", "web")
+        .insert("category", "iaas")
+        .build()
+}
@@ -0,0 +1,839 @@
+//! A local, in-process stand-in for the DigitalOcean API v2, for testing without network access.
+//!
+//! `MockServer` listens on a random port on `127.0.0.1` and serves stateful, paginated fakes of
+//! the following endpoints:
+//!
+//! * `/v2/account`
+//! * `/v2/account/keys`
+//! * `/v2/actions`
+//! * `/v2/domains`
+//! * `/v2/droplets` (including droplet actions)
+//! * `/v2/images` (including image actions)
+//!
+//! Anything created through the API (or seeded with the `add_*` methods) is kept until the server
+//! is dropped. Any other endpoint can be faked with a canned response through `MockServer::stub`,
+//! and the latest request inspected with `MockServer::last_request`.
+//!
+//! `MockSpaces` does the same for the S3 API of Spaces (see `doapi::spaces`), keeping buckets and
+//! objects in memory. This module is only available with the `testing` feature.
+//!
+//! # Example
+//!
+//! ```
+//! # use doapi::DoRequest;
+//! use doapi::testing::MockServer;
+//!
+//! let server = MockServer::start();
+//! server.add_droplet("web-01");
+//! let domgr = server.manager("fake-token");
+//! let droplets = domgr.droplets().retrieve().unwrap();
+//! assert_eq!(droplets.len(), 1);
+//! ```
+
+use std::cmp;
+use std::io::Read;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hyper::method::Method;
+use hyper::server::{Listening, Request, Response, Server};
+use hyper::status::StatusCode;
+use hyper::uri::RequestUri;
+use serde_json::{self, Value};
+use serde_json::builder::ObjectBuilder;
+
+use DoManager;
+
+#[doc(hidden)]
+pub mod fixtures;
+mod spaces;
+
+pub use self::spaces::{MockSpaces, ACCESS_KEY, SECRET_KEY};
+
+const DEFAULT_PER_PAGE: usize = 20;
+const MAX_PER_PAGE: usize = 200;
+const RATE_LIMIT: u64 = 5000;
+
+/// A running fake DigitalOcean API. The server is shut down when this value is dropped.
+pub struct MockServer {
+    listening: Listening,
+    root: String,
+    state: Arc<Mutex<State>>,
+}
+
+impl MockServer {
+    /// Starts a new server on a random local port, seeded with a few public images
+    ///
+    /// # Panics
+    ///
+    /// If no local port could be bound
+    pub fn start() -> MockServer {
+        let state = Arc::new(Mutex::new(State::new()));
+        let handler_state = state.clone();
+        let server = Server::http("127.0.0.1:0").expect("failed to bind MockServer");
+        let listening = server.handle(move |mut req: Request, mut res: Response| {
+                                  let path = match req.uri {
+                                      RequestUri::AbsolutePath(ref p) => p.clone(),
+                                      _ => String::new(),
+                                  };
+                                  let authorized = req.headers
+                                                      .get_raw("authorization")
+                                                      .and_then(|v| v.get(0))
+                                                      .map(|v| {
+                                                          let v = String::from_utf8_lossy(&v[..]);
+                                                          v.starts_with("Bearer ") &&
+                                                          v.len() > "Bearer ".len()
+                                                      })
+                                                      .unwrap_or(false);
+                                  let mut body = String::new();
+                                  let _ = req.read_to_string(&mut body);
+                                  let (reply, remaining) = {
+                                      let mut state = handler_state.lock().unwrap();
+                                      state.remaining = state.remaining.saturating_sub(1);
+                                      state.requests += 1;
+                                      state.last_request = Some((req.method.clone(),
+                                                                 path.clone(),
+                                                                 body.clone()));
+                                      let reply = if state.failures > 0 {
+                                          state.failures -= 1;
+                                          Reply::error(state.failure_status,
+                                                       "server_error",
+                                                       "Server was unable to give you a response.")
+                                      } else if authorized {
+                                          state.handle(&req.method, &path[..], &body[..])
+                                      } else {
+                                          Reply::error(401,
+                                                       "unauthorized",
+                                                       "Unable to authenticate you.")
+                                      };
+                                      (reply, state.remaining)
+                                  };
+                                  reply.write(res, remaining);
+                              })
+                              .expect("failed to start MockServer");
+        let root = format!("http://{}", listening.socket);
+        state.lock().unwrap().root = root.clone();
+        MockServer {
+            listening: listening,
+            root: root,
+            state: state,
+        }
+    }
+
+    /// The base URL to hand to `DoManager` (i.e. `http://127.0.0.1:36541/v2`)
+    pub fn url(&self) -> String { format!("{}/v2", self.root) }
+
+    /// Returns a `DoManager` which sends all requests to this server
+    pub fn manager<'t>(&self, token: &'t str) -> DoManager<'t> {
+        DoManager::builder()
+            .token(token)
+            .base_url(self.url())
+            .build()
+            .expect("MockServer URLs are valid")
+    }
+
+    /// Sets the number of items per page used when a request doesn't specify `per_page`
+    /// (defaults to `20`, like DigitalOcean)
+    pub fn set_per_page(&self, per_page: usize) {
+        self.state.lock().unwrap().per_page = per_page;
+    }
+
+    /// Sets how many times an action must be polled before it moves from `in-progress` to
+    /// `completed` (defaults to `0`, i.e. the first poll sees it completed)
+    pub fn set_action_polls(&self, polls: u32) { self.state.lock().unwrap().action_polls = polls; }
+
+    /// Answers the next `times` requests with an HTTP `status` error, regardless of the endpoint
+    pub fn fail_next(&self, status: u16, times: u32) {
+        let mut state = self.state.lock().unwrap();
+        state.failure_status = status;
+        state.failures = times;
+    }
+
+    /// Sets the number of requests left in the rate limit quota reported to the client
+    pub fn set_rate_limit_remaining(&self, remaining: u64) {
+        self.state.lock().unwrap().remaining = remaining;
+    }
+
+    /// Answers every `method` request for `path` (i.e. `/v2/volumes`, ignoring the query string)
+    /// with `status` and the JSON `body`, taking precedence over the built-in endpoints
+    pub fn stub(&self, method: Method, path: &str, status: u16, body: &str) {
+        self.stub_raw(method,
+                      path,
+                      status,
+                      "application/json; charset=utf-8",
+                      body.as_bytes());
+    }
+
+    /// Like `stub`, but for responses which aren't JSON (i.e. CSV or YAML)
+    pub fn stub_raw(&self,
+                    method: Method,
+                    path: &str,
+                    status: u16,
+                    content_type: &str,
+                    body: &[u8]) {
+        let mut state = self.state.lock().unwrap();
+        state.stubs.retain(|s| !(s.method == method && s.path == path));
+        state.stubs.push(Stub {
+            method: method,
+            path: path.to_owned(),
+            status: status,
+            content_type: content_type.to_owned(),
+            body: body.to_vec(),
+        });
+    }
+
+    /// Returns the method, URI (path and query string) and body of the latest request received
+    pub fn last_request(&self) -> Option<(Method, String, String)> {
+        self.state.lock().unwrap().last_request.clone()
+    }
+
+    /// Returns the number of requests received so far
+    pub fn requests(&self) -> u64 { self.state.lock().unwrap().requests }
+
+    /// When `fail` is `true`, actions created from now on finish as `errored` rather than
+    /// `completed`
+    pub fn fail_actions(&self, fail: bool) { self.state.lock().unwrap().fail_actions = fail; }
+
+    /// Seeds a new active droplet in `nyc3` and returns its ID
+    pub fn add_droplet(&self, name: &str) -> u64 {
+        let mut state = self.state.lock().unwrap();
+        let image = state.images[0].1.clone();
+        state.create_droplet(name, "nyc3", "512mb", image)
+    }
+
+    /// Seeds a new domain pointing at `ip`
+    pub fn add_domain(&self, name: &str, ip: &str) {
+        self.state.lock().unwrap().domains.push(fixtures::domain(name, ip));
+    }
+
+    /// Seeds a new SSH key and returns its ID
+    pub fn add_ssh_key(&self, name: &str, public_key: &str) -> u64 {
+        let mut state = self.state.lock().unwrap();
+        let id = state.next_id();
+        state.ssh_keys.push(fixtures::ssh_key(id, name, public_key));
+        id
+    }
+
+    /// Seeds a new private snapshot image and returns its ID
+    pub fn add_image(&self, name: &str) -> u64 {
+        let mut state = self.state.lock().unwrap();
+        let id = state.next_id();
+        state.images.push(("private", fixtures::image(id, name, None, false, "snapshot", "nyc3")));
+        id
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) { let _ = self.listening.close(); }
+}
+
+struct Reply {
+    status: u16,
+    body: Option<Value>,
+    // A non-JSON body as (content type, bytes), which takes precedence over `body`
+    raw: Option<(String, Vec<u8>)>,
+}
+
+impl Reply {
+    fn ok(status: u16, key: &str, v: Value) -> Reply {
+        Reply {
+            status: status,
+            raw: None,
+            body: Some(ObjectBuilder::new().insert(key, v).build()),
+        }
+    }
+
+    fn no_content() -> Reply {
+        Reply {
+            status: 204,
+            raw: None,
+            body: None,
+        }
+    }
+
+    fn error(status: u16, id: &str, message: &str) -> Reply {
+        Reply {
+            status: status,
+            raw: None,
+            body: Some(fixtures::error(id, message)),
+        }
+    }
+
+    fn not_found() -> Reply {
+        Reply::error(404,
+                     "not_found",
+                     "The resource you were accessing could not be found.")
+    }
+
+    fn write(self, mut res: Response, remaining: u64) {
+        let reset = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map(|d| d.as_secs() + 3600)
+                        .unwrap_or(0);
+        *res.status_mut() = StatusCode::from_u16(self.status);
+        let (content_type, body) = match (self.raw, self.body) {
+            (Some((t, raw)), _) => (t.into_bytes(), raw),
+            (None, Some(ref v)) => {
+                (b"application/json; charset=utf-8".to_vec(),
+                 serde_json::to_string(v).unwrap_or(String::new()).into_bytes())
+            }
+            (None, None) => (b"application/octet-stream".to_vec(), vec![]),
+        };
+        {
+            let headers = res.headers_mut();
+            headers.set_raw("content-type", vec![content_type]);
+            headers.set_raw("ratelimit-limit", vec![RATE_LIMIT.to_string().into_bytes()]);
+            headers.set_raw("ratelimit-remaining", vec![remaining.to_string().into_bytes()]);
+            headers.set_raw("ratelimit-reset", vec![reset.to_string().into_bytes()]);
+        }
+        let _ = res.send(&body[..]);
+    }
+}
+
+struct Stub {
+    method: Method,
+    path: String,
+    status: u16,
+    content_type: String,
+    body: Vec<u8>,
+}
+
+struct MockAction {
+    value: Value,
+    polls: u32,
+    fail: bool,
+}
+
+struct State {
+    root: String,
+    last_id: u64,
+    per_page: usize,
+    action_polls: u32,
+    fail_actions: bool,
+    failures: u32,
+    failure_status: u16,
+    requests: u64,
+    last_request: Option<(Method, String, String)>,
+    stubs: Vec<Stub>,
+    remaining: u64,
+    droplets: Vec<Value>,
+    domains: Vec<Value>,
+    ssh_keys: Vec<Value>,
+    // (kind, image) where kind is one of "distribution", "application" or "private"
+    images: Vec<(&'static str, Value)>,
+    actions: Vec<MockAction>,
+}
+
+impl State {
+    fn new() -> State {
+        State {
+            root: String::new(),
+            last_id: 3000000,
+            per_page: DEFAULT_PER_PAGE,
+            action_polls: 0,
+            fail_actions: false,
+            failures: 0,
+            failure_status: 500,
+            requests: 0,
+            last_request: None,
+            stubs: vec![],
+            remaining: RATE_LIMIT,
+            droplets: vec![],
+            domains: vec![],
+            ssh_keys: vec![],
+            images: vec![("distribution",
+                          fixtures::image(6918990,
+                                          "14.04 x64",
+                                          Some("ubuntu-14-04-x64"),
+                                          true,
+                                          "snapshot",
+                                          "nyc3")),
+                         ("distribution",
+                          fixtures::image(17154032,
+                                          "16.04.1 x64",
+                                          Some("ubuntu-16-04-x64"),
+                                          true,
+                                          "snapshot",
+                                          "nyc3")),
+                         ("application",
+                          fixtures::image(6376601,
+                                          "Ruby on Rails on 14.04 (Nginx + Unicorn)",
+                                          Some("ruby-on-rails"),
+                                          true,
+                                          "snapshot",
+                                          "nyc3"))],
+            actions: vec![],
+        }
+    }
+
+    fn next_id(&mut self) -> u64 {
+        self.last_id += 1;
+        self.last_id
+    }
+
+    fn handle(&mut self, method: &Method, uri: &str, body: &str) -> Reply {
+        let (path, query) = match uri.find('?') {
+            Some(i) => (&uri[..i], parse_query(&uri[i + 1..])),
+            None => (uri, vec![]),
+        };
+        if let Some(stub) = self.stubs.iter().find(|s| &s.method == method && s.path == path) {
+            return Reply {
+                status: stub.status,
+                body: None,
+                raw: Some((stub.content_type.clone(), stub.body.clone())),
+            };
+        }
+        let segs: Vec<&str> = path.trim_matches('/').split('/').collect();
+        if segs[0] != "v2" {
+            return Reply::not_found();
+        }
+        let body = serde_json::from_str::<Value>(body).unwrap_or(Value::Null);
+        let req = Req {
+            method: method.clone(),
+            path: path,
+            query: query,
+            body: body,
+        };
+        match segs.get(1).cloned() {
+            Some("account") => self.account(&req, &segs[2..]),
+            Some("actions") => self.actions(&req, &segs[2..]),
+            Some("domains") => self.domains(&req, &segs[2..]),
+            Some("droplets") => self.droplets(&req, &segs[2..]),
+            Some("images") => self.images(&req, &segs[2..]),
+            _ => Reply::not_found(),
+        }
+    }
+
+    fn account(&mut self, req: &Req, segs: &[&str]) -> Reply {
+        match (req.method.clone(), segs.len()) {
+            (Method::Get, 0) => Reply::ok(200, "account", fixtures::account()),
+            (_, n) if n > 0 && segs[0] == "keys" => self.ssh_keys(req, &segs[1..]),
+            _ => Reply::not_found(),
+        }
+    }
+
+    fn ssh_keys(&mut self, req: &Req, segs: &[&str]) -> Reply {
+        match (req.method.clone(), segs.len()) {
+            (Method::Get, 0) => self.page(req, "ssh_keys", self.ssh_keys.clone()),
+            (Method::Post, 0) => {
+                let (name, key) = match (req.str_field("name"), req.str_field("public_key")) {
+                    (Some(n), Some(k)) => (n, k),
+                    _ => return unprocessable("Name and public key are required"),
+                };
+                let id = self.next_id();
+                let ssh_key = fixtures::ssh_key(id, &name[..], &key[..]);
+                self.ssh_keys.push(ssh_key.clone());
+                Reply::ok(201, "ssh_key", ssh_key)
+            }
+            (method, 1) => {
+                let idx = match self.ssh_keys.iter().position(|k| {
+                    matches_field(k, "id", segs[0]) || matches_field(k, "fingerprint", segs[0])
+                }) {
+                    Some(i) => i,
+                    None => return Reply::not_found(),
+                };
+                match method {
+                    Method::Get => Reply::ok(200, "ssh_key", self.ssh_keys[idx].clone()),
+                    Method::Put => {
+                        if let Some(name) = req.str_field("name") {
+                            set_field(&mut self.ssh_keys[idx], "name", Value::String(name));
+                        }
+                        Reply::ok(200, "ssh_key", self.ssh_keys[idx].clone())
+                    }
+                    Method::Delete => {
+                        self.ssh_keys.remove(idx);
+                        Reply::no_content()
+                    }
+                    _ => Reply::not_found(),
+                }
+            }
+            _ => Reply::not_found(),
+        }
+    }
+
+    fn actions(&mut self, req: &Req, segs: &[&str]) -> Reply {
+        match (req.method.clone(), segs.len()) {
+            (Method::Get, 0) => {
+                let all = self.actions.iter().map(|a| a.value.clone()).collect();
+                self.page(req, "actions", all)
+            }
+            (Method::Get, 1) => self.poll_action(segs[0], None),
+            _ => Reply::not_found(),
+        }
+    }
+
+    fn domains(&mut self, req: &Req, segs: &[&str]) -> Reply {
+        match (req.method.clone(), segs.len()) {
+            (Method::Get, 0) => self.page(req, "domains", self.domains.clone()),
+            (Method::Post, 0) => {
+                let (name, ip) = match (req.str_field("name"), req.str_field("ip_address")) {
+                    (Some(n), Some(i)) => (n, i),
+                    _ => return unprocessable("Name and IP address are required"),
+                };
+                if self.domains.iter().any(|d| matches_field(d, "name", &name[..])) {
+                    return unprocessable("Name has already been taken");
+                }
+                let domain = fixtures::domain(&name[..], &ip[..]);
+                self.domains.push(domain.clone());
+                Reply::ok(201, "domain", domain)
+            }
+            (method, 1) => {
+                let idx = match self.domains.iter().position(|d| matches_field(d, "name", segs[0])) {
+                    Some(i) => i,
+                    None => return Reply::not_found(),
+                };
+                match method {
+                    Method::Get => Reply::ok(200, "domain", self.domains[idx].clone()),
+                    Method::Delete => {
+                        self.domains.remove(idx);
+                        Reply::no_content()
+                    }
+                    _ => Reply::not_found(),
+                }
+            }
+            _ => Reply::not_found(),
+        }
+    }
+
+    fn droplets(&mut self, req: &Req, segs: &[&str]) -> Reply {
+        if segs.is_empty() {
+            return match req.method {
+                Method::Get => self.page(req, "droplets", self.droplets.clone()),
+                Method::Post => self.create_droplet_from(req),
+                _ => Reply::not_found(),
+            };
+        }
+        let idx = match self.droplets.iter().position(|d| matches_field(d, "id", segs[0])) {
+            Some(i) => i,
+            None => return Reply::not_found(),
+        };
+        let id = segs[0].parse::<u64>().unwrap_or(0);
+        match (req.method.clone(), segs.len()) {
+            (Method::Get, 1) => Reply::ok(200, "droplet", self.droplets[idx].clone()),
+            (Method::Delete, 1) => {
+                self.droplets.remove(idx);
+                Reply::no_content()
+            }
+            (Method::Get, 2) if segs[1] == "actions" => {
+                let actions = self.actions_for(id, "droplet");
+                self.page(req, "actions", actions)
+            }
+            (Method::Post, 2) if segs[1] == "actions" => {
+                let action_type = match req.str_field("type") {
+                    Some(t) => t,
+                    None => return unprocessable("Type is required"),
+                };
+                self.droplet_action(idx, id, &action_type[..], req)
+            }
+            (Method::Get, 3) if segs[1] == "actions" => self.poll_action(segs[2], Some(id)),
+            _ => Reply::not_found(),
+        }
+    }
+
+    fn images(&mut self, req: &Req, segs: &[&str]) -> Reply {
+        if segs.is_empty() {
+            return match req.method {
+                Method::Get => {
+                    let kind = req.param("type").map(|t| t.trim_right_matches('s').to_owned());
+                    let private = req.param("private").map(|p| p == "true").unwrap_or(false);
+                    let images = self.images
+                                     .iter()
+                                     .filter(|&&(k, _)| {
+                                         match kind {
+                                             Some(ref t) if t != "available" => k == &t[..],
+                                             _ => !private || k == "private",
+                                         }
+                                     })
+                                     .map(|&(_, ref v)| v.clone())
+                                     .collect();
+                    self.page(req, "images", images)
+                }
+                _ => Reply::not_found(),
+            };
+        }
+        let idx = match self.images.iter().position(|&(_, ref i)| {
+            matches_field(i, "id", segs[0]) || matches_field(i, "slug", segs[0])
+        }) {
+            Some(i) => i,
+            None => return Reply::not_found(),
+        };
+        let id = self.images[idx].1.find("id").and_then(|v| v.as_u64()).unwrap_or(0);
+        match (req.method.clone(), segs.len()) {
+            (Method::Get, 1) => Reply::ok(200, "image", self.images[idx].1.clone()),
+            (Method::Put, 1) => {
+                if let Some(name) = req.str_field("name") {
+                    set_field(&mut self.images[idx].1, "name", Value::String(name));
+                }
+                Reply::ok(200, "image", self.images[idx].1.clone())
+            }
+            (Method::Delete, 1) => {
+                self.images.remove(idx);
+                Reply::no_content()
+            }
+            (Method::Get, 2) if segs[1] == "actions" => {
+                let actions = self.actions_for(id, "image");
+                self.page(req, "actions", actions)
+            }
+            (Method::Post, 2) if segs[1] == "actions" => {
+                let action_type = match req.str_field("type") {
+                    Some(t) => t,
+                    None => return unprocessable("Type is required"),
+                };
+                let region = req.str_field("region").unwrap_or("nyc3".to_owned());
+                let action = self.new_action(&action_type[..], id, "image", &region[..]);
+                Reply::ok(201, "action", action)
+            }
+            (Method::Get, 3) if segs[1] == "actions" => self.poll_action(segs[2], Some(id)),
+            _ => Reply::not_found(),
+        }
+    }
+
+    fn create_droplet_from(&mut self, req: &Req) -> Reply {
+        let (name, region, size) = match (req.str_field("name"),
+                                          req.str_field("region"),
+                                          req.str_field("size")) {
+            (Some(n), Some(r), Some(s)) => (n, r, s),
+            _ => return unprocessable("Name, region, size and image are required"),
+        };
+        let image = match req.body.find("image") {
+            Some(&Value::String(ref s)) => s.clone(),
+            Some(v) if v.is_u64() => v.as_u64().unwrap().to_string(),
+            _ => return unprocessable("Name, region, size and image are required"),
+        };
+        let image = match self.images.iter().find(|&&(_, ref i)| {
+            matches_field(i, "id", &image[..]) || matches_field(i, "slug", &image[..])
+        }) {
+            Some(&(_, ref i)) => i.clone(),
+            None => return unprocessable("You specified an invalid image for Droplet creation."),
+        };
+        let id = self.create_droplet(&name[..], &region[..], &size[..], image);
+        if let Some(vpc) = req.str_field("vpc_uuid") {
+            if let Some(d) = self.droplets.last_mut() {
+                set_field(d, "vpc_uuid", Value::String(vpc));
+            }
+        }
+        let mut droplet = self.droplets.last().cloned().unwrap_or(Value::Null);
+        set_field(&mut droplet, "status", Value::String("new".to_owned()));
+        let action = self.new_action("create", id, "droplet", &region[..]);
+        let action_id = action.find("id").cloned().unwrap_or(Value::Null);
+        Reply {
+            status: 202,
+            raw: None,
+            body: Some(ObjectBuilder::new()
+                           .insert("droplet", droplet)
+                           .insert_object("links", |l| {
+                               l.insert_array("actions", |a| {
+                                   a.push_object(|o| {
+                                       o.insert("id", action_id)
+                                        .insert("rel", "create")
+                                        .insert("href", "")
+                                   })
+                               })
+                           })
+                           .build()),
+        }
+    }
+
+    fn create_droplet(&mut self, name: &str, region: &str, size: &str, image: Value) -> u64 {
+        let id = self.next_id();
+        self.droplets.push(fixtures::droplet(id, name, region, size, image));
+        id
+    }
+
+    fn droplet_action(&mut self, idx: usize, id: u64, action_type: &str, req: &Req) -> Reply {
+        let region = self.droplets[idx]
+                         .lookup("region.slug")
+                         .and_then(|v| v.as_string())
+                         .unwrap_or("nyc3")
+                         .to_owned();
+        match action_type {
+            "rename" => {
+                if let Some(name) = req.str_field("name") {
+                    set_field(&mut self.droplets[idx], "name", Value::String(name));
+                }
+            }
+            "power_off" | "shutdown" => {
+                set_field(&mut self.droplets[idx], "status", Value::String("off".to_owned()))
+            }
+            "power_on" | "reboot" | "power_cycle" => {
+                set_field(&mut self.droplets[idx], "status", Value::String("active".to_owned()))
+            }
+            "snapshot" => {
+                let name = req.str_field("name").unwrap_or(format!("snapshot-{}", id));
+                let img_id = self.next_id();
+                self.images.push(("private",
+                                  fixtures::image(img_id,
+                                                  &name[..],
+                                                  None,
+                                                  false,
+                                                  "snapshot",
+                                                  &region[..])));
+            }
+            "resize" => {
+                if let Some(size) = req.str_field("size") {
+                    set_field(&mut self.droplets[idx], "size", fixtures::size(&size[..]));
+                    set_field(&mut self.droplets[idx], "size_slug", Value::String(size));
+                }
+            }
+            _ => (),
+        }
+        let action = self.new_action(action_type, id, "droplet", &region[..]);
+        Reply::ok(201, "action", action)
+    }
+
+    fn new_action(&mut self, action_type: &str, resource_id: u64, resource: &str, region: &str)
+                  -> Value {
+        let id = self.next_id();
+        let action = fixtures::action(id,
+                                      "in-progress",
+                                      action_type,
+                                      resource_id,
+                                      resource,
+                                      region);
+        self.actions.push(MockAction {
+            value: action.clone(),
+            polls: self.action_polls,
+            fail: self.fail_actions,
+        });
+        action
+    }
+
+    fn actions_for(&self, resource_id: u64, resource: &str) -> Vec<Value> {
+        self.actions
+            .iter()
+            .filter(|a| {
+                a.value.find("resource_id").and_then(|v| v.as_u64()) == Some(resource_id) &&
+                a.value.find("resource_type").and_then(|v| v.as_string()) == Some(resource)
+            })
+            .map(|a| a.value.clone())
+            .collect()
+    }
+
+    // Every poll of an in-progress action brings it one step closer to completion (or failure)
+    fn poll_action(&mut self, id: &str, resource_id: Option<u64>) -> Reply {
+        let action = match self.actions.iter_mut().find(|a| {
+            let owner = a.value.find("resource_id").and_then(|v| v.as_u64());
+            matches_field(&a.value, "id", id) && resource_id.map_or(true, |r| owner == Some(r))
+        }) {
+            Some(a) => a,
+            None => return Reply::not_found(),
+        };
+        let in_progress = action.value.find("status").and_then(|v| v.as_string()) ==
+                          Some("in-progress");
+        if in_progress {
+            if action.polls == 0 {
+                let status = if action.fail {
+                    "errored"
+                } else {
+                    "completed"
+                };
+                set_field(&mut action.value, "status", Value::String(status.to_owned()));
+                set_field(&mut action.value,
+                          "completed_at",
+                          Value::String(fixtures::CREATED_AT.to_owned()));
+            } else {
+                action.polls -= 1;
+            }
+        }
+        Reply::ok(200, "action", action.value.clone())
+    }
+
+    fn page(&self, req: &Req, key: &str, items: Vec<Value>) -> Reply {
+        let per_page = cmp::min(cmp::max(req.param("per_page")
+                                               .and_then(|p| p.parse::<usize>().ok())
+                                               .unwrap_or(self.per_page),
+                                            1),
+                                 MAX_PER_PAGE);
+        let total = items.len();
+        let last = if total == 0 {
+            1
+        } else {
+            (total + per_page - 1) / per_page
+        };
+        let page = cmp::max(req.param("page").and_then(|p| p.parse::<usize>().ok()).unwrap_or(1),
+                            1);
+        let collection: Vec<Value> = items.into_iter()
+                                          .skip((page - 1) * per_page)
+                                          .take(per_page)
+                                          .collect();
+        let link = |n: usize| {
+            let mut query: Vec<String> = req.query
+                                            .iter()
+                                            .filter(|&&(ref k, _)| k != "page")
+                                            .map(|&(ref k, ref v)| format!("{}={}", k, v))
+                                            .collect();
+            query.push(format!("page={}", n));
+            format!("{}{}?{}", self.root, req.path, query.join("&"))
+        };
+        let mut pages = ObjectBuilder::new();
+        if page > 1 {
+            pages = pages.insert("first", link(1)).insert("prev", link(page - 1));
+        }
+        if page < last {
+            pages = pages.insert("next", link(page + 1)).insert("last", link(last));
+        }
+        let pages = pages.build();
+        let has_pages = pages.as_object().map_or(false, |p| !p.is_empty());
+        Reply {
+            status: 200,
+            raw: None,
+            body: Some(ObjectBuilder::new()
+                           .insert(key, fixtures::array(collection))
+                           .insert_object("links", |l| {
+                               if has_pages {
+                                   l.insert("pages", pages)
+                               } else {
+                                   l
+                               }
+                           })
+                           .insert_object("meta", |m| m.insert("total", total))
+                           .build()),
+        }
+    }
+}
+
+struct Req<'a> {
+    method: Method,
+    path: &'a str,
+    query: Vec<(String, String)>,
+    body: Value,
+}
+
+impl<'a> Req<'a> {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.query.iter().find(|&&(ref k, _)| k == name).map(|&(_, ref v)| &v[..])
+    }
+
+    fn str_field(&self, name: &str) -> Option<String> {
+        self.body.find(name).and_then(|v| v.as_string()).map(|s| s.to_owned())
+    }
+}
+
+fn unprocessable(message: &str) -> Reply { Reply::error(422, "unprocessable_entity", message) }
+
+fn parse_query(q: &str) -> Vec<(String, String)> {
+    q.split('&')
+     .filter(|kv| !kv.is_empty())
+     .map(|kv| {
+         match kv.find('=') {
+             Some(i) => (kv[..i].to_owned(), kv[i + 1..].to_owned()),
+             None => (kv.to_owned(), String::new()),
+         }
+     })
+     .collect()
+}
+
+fn matches_field(v: &Value, field: &str, expected: &str) -> bool {
+    match v.find(field) {
+        Some(&Value::String(ref s)) => s == expected,
+        Some(&Value::U64(n)) => n.to_string() == expected,
+        Some(&Value::I64(n)) => n.to_string() == expected,
+        _ => false,
+    }
+}
+
+fn set_field(v: &mut Value, field: &str, new: Value) {
+    if let Some(obj) = v.as_object_mut() {
+        obj.insert(field.to_owned(), new);
+    }
+}
@@ -0,0 +1,689 @@
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::sync::{Arc, Mutex};
+
+use hyper::method::Method;
+use hyper::server::{Listening, Request, Response, Server};
+use hyper::status::StatusCode;
+use hyper::uri::RequestUri;
+use url::form_urlencoded;
+use url::percent_encoding::percent_decode;
+
+use spaces::{Spaces, MIN_PART_SIZE};
+use spaces::sigv4::payload_hash;
+use spaces::xml;
+
+/// The access key `MockSpaces` accepts, any other is rejected as `InvalidAccessKeyId`
+pub const ACCESS_KEY: &'static str = "DO00MOCKSPACESKEY000";
+/// The secret key handed out by `MockSpaces::client` (signatures aren't verified)
+pub const SECRET_KEY: &'static str = "mock-spaces-secret";
+
+const NAMESPACE: &'static str = "http://s3.amazonaws.com/doc/2006-03-01/";
+const ALL_USERS: &'static str = "http://acs.amazonaws.com/groups/global/AllUsers";
+const OWNER_ID: &'static str = "6174283";
+const REQUEST_ID: &'static str = "tx00000000000000000000-mock";
+const TIMESTAMP: &'static str = "2016-03-01T12:00:00.000Z";
+const DEFAULT_MAX_KEYS: usize = 1000;
+
+/// A running fake of the S3 API implemented by Spaces. The server is shut down when this value
+/// is dropped.
+///
+/// Buckets are addressed path-style (`/$BUCKET/$KEY`), and buckets, objects, ACLs and multipart
+/// uploads are kept in memory. Requests must either be presigned or carry an `AWS4-HMAC-SHA256`
+/// `Authorization` header for `ACCESS_KEY` along with an `x-amz-content-sha256` header matching
+/// the body. The signatures themselves aren't verified.
+///
+/// # Example
+///
+/// ```
+/// use doapi::spaces::Acl;
+/// use doapi::testing::MockSpaces;
+///
+/// let server = MockSpaces::start();
+/// let spaces = server.client();
+/// spaces.create_bucket("assets").unwrap();
+/// spaces.put_object("assets", "logo.svg", b"<svg/>", "image/svg+xml", Acl::PublicRead).unwrap();
+/// assert_eq!(spaces.get_object("assets", "logo.svg").unwrap(), b"<svg/>");
+/// ```
+pub struct MockSpaces {
+    listening: Listening,
+    root: String,
+    state: Arc<Mutex<State>>,
+}
+
+impl MockSpaces {
+    /// Starts a new server without any buckets on a random local port
+    ///
+    /// # Panics
+    ///
+    /// If no local port could be bound
+    pub fn start() -> MockSpaces {
+        let state = Arc::new(Mutex::new(State::new()));
+        let handler_state = state.clone();
+        let server = Server::http("127.0.0.1:0").expect("failed to bind MockSpaces");
+        let listening = server.handle(move |mut req: Request, res: Response| {
+                                  let uri = match req.uri {
+                                      RequestUri::AbsolutePath(ref p) => p.clone(),
+                                      _ => String::new(),
+                                  };
+                                  let headers: Vec<(String, String)> =
+                                      req.headers
+                                         .iter()
+                                         .map(|h| (h.name().to_lowercase(), h.value_string()))
+                                         .collect();
+                                  let mut body = vec![];
+                                  let _ = req.read_to_end(&mut body);
+                                  let reply = handler_state.lock()
+                                                           .unwrap()
+                                                           .handle(&req.method,
+                                                                   &uri[..],
+                                                                   &headers[..],
+                                                                   &body[..]);
+                                  reply.write(res);
+                              })
+                              .expect("failed to start MockSpaces");
+        let root = format!("http://{}", listening.socket);
+        MockSpaces {
+            listening: listening,
+            root: root,
+            state: state,
+        }
+    }
+
+    /// The endpoint to hand to `SpacesBuilder::endpoint` (i.e. `http://127.0.0.1:36541`)
+    pub fn url(&self) -> String { self.root.clone() }
+
+    /// Returns a `Spaces` client for the `nyc3` region which sends all requests to this server,
+    /// signed with `ACCESS_KEY` and `SECRET_KEY`
+    pub fn client(&self) -> Spaces {
+        Spaces::builder()
+            .keys(ACCESS_KEY, SECRET_KEY)
+            .region("nyc3")
+            .endpoint(self.url())
+            .build()
+            .expect("MockSpaces URLs are valid")
+    }
+
+    /// Sets the number of keys (and common prefixes) listed per request when a request doesn't
+    /// specify `max-keys` (defaults to `1000`, like S3)
+    pub fn set_max_keys(&self, max_keys: usize) {
+        self.state.lock().unwrap().max_keys = max_keys;
+    }
+
+    /// Answers the next `times` requests with an HTTP `status` error, regardless of the endpoint
+    pub fn fail_next(&self, status: u16, times: u32) {
+        let mut state = self.state.lock().unwrap();
+        state.failure_status = status;
+        state.failures = times;
+    }
+
+    /// Returns the method and URI (path and query string) of the latest request received
+    pub fn last_request(&self) -> Option<(Method, String)> {
+        self.state.lock().unwrap().last_request.clone()
+    }
+
+    /// Returns the number of requests received so far
+    pub fn requests(&self) -> u64 { self.state.lock().unwrap().requests }
+
+    /// Returns the number of multipart uploads which have been initiated, but neither completed
+    /// nor aborted
+    pub fn pending_uploads(&self) -> usize { self.state.lock().unwrap().uploads.len() }
+}
+
+impl Drop for MockSpaces {
+    fn drop(&mut self) { let _ = self.listening.close(); }
+}
+
+struct Reply {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl Reply {
+    fn ok() -> Reply {
+        Reply {
+            status: 200,
+            headers: vec![],
+            body: vec![],
+        }
+    }
+
+    fn xml(body: String) -> Reply {
+        Reply {
+            status: 200,
+            headers: vec![("content-type".to_owned(), "application/xml".to_owned())],
+            body: format!(r#"<?xml version="1.0" encoding="UTF-8"?>{}"#, body).into_bytes(),
+        }
+    }
+
+    fn no_content() -> Reply {
+        Reply {
+            status: 204,
+            headers: vec![],
+            body: vec![],
+        }
+    }
+
+    fn error(status: u16, code: &str, message: &str) -> Reply {
+        let mut reply = Reply::xml(format!("<Error><Code>{}</Code><Message>{}</Message>\
+                                            <RequestId>{}</RequestId></Error>",
+                                           code,
+                                           xml::escape(message),
+                                           REQUEST_ID));
+        reply.status = status;
+        reply
+    }
+
+    fn no_such_bucket() -> Reply {
+        Reply::error(404,
+                     "NoSuchBucket",
+                     "The specified bucket does not exist.")
+    }
+
+    fn no_such_key() -> Reply {
+        Reply::error(404, "NoSuchKey", "The specified key does not exist.")
+    }
+
+    fn no_such_upload() -> Reply {
+        Reply::error(404,
+                     "NoSuchUpload",
+                     "The specified multipart upload does not exist.")
+    }
+
+    fn with_header(mut self, name: &str, value: &str) -> Reply {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    fn write(self, mut res: Response) {
+        *res.status_mut() = StatusCode::from_u16(self.status);
+        {
+            let headers = res.headers_mut();
+            headers.set_raw("x-amz-request-id", vec![REQUEST_ID.as_bytes().to_vec()]);
+            for (name, value) in self.headers {
+                headers.set_raw(name, vec![value.into_bytes()]);
+            }
+        }
+        let _ = res.send(&self.body[..]);
+    }
+}
+
+struct MockObject {
+    data: Vec<u8>,
+    etag: String,
+    content_type: String,
+    acl: String,
+}
+
+struct MockUpload {
+    bucket: String,
+    key: String,
+    content_type: String,
+    acl: String,
+    // part number -> (etag, data)
+    parts: BTreeMap<u32, (String, Vec<u8>)>,
+}
+
+// A listed object, or a common prefix rolling up several objects
+enum Entry<'a> {
+    Object(&'a str, &'a MockObject),
+    Prefix(String),
+}
+
+struct State {
+    last_id: u64,
+    max_keys: usize,
+    failures: u32,
+    failure_status: u16,
+    requests: u64,
+    last_request: Option<(Method, String)>,
+    buckets: BTreeMap<String, BTreeMap<String, MockObject>>,
+    uploads: BTreeMap<String, MockUpload>,
+}
+
+impl State {
+    fn new() -> State {
+        State {
+            last_id: 0,
+            max_keys: DEFAULT_MAX_KEYS,
+            failures: 0,
+            failure_status: 500,
+            requests: 0,
+            last_request: None,
+            buckets: BTreeMap::new(),
+            uploads: BTreeMap::new(),
+        }
+    }
+
+    fn handle(&mut self,
+              method: &Method,
+              uri: &str,
+              headers: &[(String, String)],
+              body: &[u8])
+              -> Reply {
+        self.requests += 1;
+        self.last_request = Some((method.clone(), uri.to_owned()));
+        if self.failures > 0 {
+            self.failures -= 1;
+            return Reply::error(self.failure_status,
+                                "InternalError",
+                                "We encountered an internal error. Please try again.");
+        }
+        let (path, query) = match uri.find('?') {
+            Some(i) => (&uri[..i], &uri[i + 1..]),
+            None => (uri, ""),
+        };
+        let query: BTreeMap<String, String> = form_urlencoded::parse(query.as_bytes())
+                                                  .map(|(k, v)| (k.into_owned(), v.into_owned()))
+                                                  .collect();
+        if let Some(denied) = authenticate(headers, &query, body) {
+            return denied;
+        }
+        let path = percent_decode(path.as_bytes()).decode_utf8_lossy().into_owned();
+        let mut segments = path.trim_left_matches('/').splitn(2, '/');
+        let bucket = segments.next().unwrap_or("");
+        let key = segments.next().unwrap_or("");
+        match (bucket, key) {
+            ("", _) if *method == Method::Get => self.list_buckets(),
+            ("", _) => method_not_allowed(),
+            (bucket, "") => self.bucket(method, bucket, &query),
+            (bucket, key) => {
+                if !self.buckets.contains_key(bucket) {
+                    return Reply::no_such_bucket();
+                }
+                self.object(method, bucket, key, &query, headers, body)
+            }
+        }
+    }
+
+    fn list_buckets(&self) -> Reply {
+        let buckets = self.buckets.keys().fold(String::new(), |acc, name| {
+            acc +
+            &format!("<Bucket><Name>{}</Name><CreationDate>{}</CreationDate></Bucket>",
+                     xml::escape(name),
+                     TIMESTAMP)[..]
+        });
+        Reply::xml(format!(r#"<ListAllMyBucketsResult xmlns="{}"><Owner><ID>{}</ID>"#,
+                           NAMESPACE,
+                           OWNER_ID) +
+                   &format!("<DisplayName>{}</DisplayName></Owner><Buckets>{}</Buckets>\
+                             </ListAllMyBucketsResult>",
+                            OWNER_ID,
+                            buckets)[..])
+    }
+
+    fn bucket(&mut self, method: &Method, name: &str, query: &BTreeMap<String, String>) -> Reply {
+        match *method {
+            Method::Put => {
+                if self.buckets.contains_key(name) {
+                    return Reply::error(409,
+                                        "BucketAlreadyExists",
+                                        "The requested bucket name is not available.");
+                }
+                self.buckets.insert(name.to_owned(), BTreeMap::new());
+                Reply::ok()
+            }
+            Method::Delete => {
+                match self.buckets.get(name).map(|objects| objects.is_empty()) {
+                    None => Reply::no_such_bucket(),
+                    Some(false) => {
+                        Reply::error(409,
+                                     "BucketNotEmpty",
+                                     "The bucket you tried to delete is not empty.")
+                    }
+                    Some(true) => {
+                        self.buckets.remove(name);
+                        Reply::no_content()
+                    }
+                }
+            }
+            Method::Get => self.list_objects(name, query),
+            _ => method_not_allowed(),
+        }
+    }
+
+    fn list_objects(&self, name: &str, query: &BTreeMap<String, String>) -> Reply {
+        let objects = match self.buckets.get(name) {
+            Some(objects) => objects,
+            None => return Reply::no_such_bucket(),
+        };
+        let prefix = query.get("prefix").map(|p| &p[..]).unwrap_or("");
+        let delimiter = query.get("delimiter").map(|d| &d[..]).unwrap_or("");
+        // Keys sharing a prefix are adjacent in a sorted map, so rolling them up only needs to
+        // look at the previous entry
+        let mut entries: Vec<Entry> = vec![];
+        for (key, object) in objects.iter().filter(|&(k, _)| k.starts_with(prefix)) {
+            let rest = &key[prefix.len()..];
+            let split = if delimiter.is_empty() {
+                None
+            } else {
+                rest.find(delimiter)
+            };
+            match split {
+                Some(i) => {
+                    let common = key[..prefix.len() + i + delimiter.len()].to_owned();
+                    let seen = match entries.last() {
+                        Some(&Entry::Prefix(ref p)) => *p == common,
+                        _ => false,
+                    };
+                    if !seen {
+                        entries.push(Entry::Prefix(common));
+                    }
+                }
+                None => entries.push(Entry::Object(&key[..], object)),
+            }
+        }
+        // Continuation tokens are simply the index of the next entry
+        let start: usize = query.get("continuation-token")
+                                .and_then(|t| t.parse().ok())
+                                .unwrap_or(0);
+        let max_keys = query.get("max-keys").and_then(|m| m.parse().ok()).unwrap_or(self.max_keys);
+        let end = ::std::cmp::min(start + max_keys, entries.len());
+        let start = ::std::cmp::min(start, end);
+        let mut contents = String::new();
+        let mut prefixes = String::new();
+        for entry in &entries[start..end] {
+            match *entry {
+                Entry::Object(key, object) => {
+                    contents.push_str(&format!("<Contents><Key>{}</Key><LastModified>{}\
+                                                </LastModified><ETag>{}</ETag><Size>{}</Size>\
+                                                <StorageClass>STANDARD</StorageClass></Contents>",
+                                               xml::escape(key),
+                                               TIMESTAMP,
+                                               xml::escape(&object.etag[..]),
+                                               object.data.len())[..]);
+                }
+                Entry::Prefix(ref p) => {
+                    prefixes.push_str(&format!("<CommonPrefixes><Prefix>{}</Prefix>\
+                                                </CommonPrefixes>",
+                                               xml::escape(&p[..]))[..]);
+                }
+            }
+        }
+        let truncated = end < entries.len();
+        let next = if truncated {
+            format!("<NextContinuationToken>{}</NextContinuationToken>", end)
+        } else {
+            String::new()
+        };
+        Reply::xml(format!(r#"<ListBucketResult xmlns="{}"><Name>{}</Name><Prefix>{}</Prefix>"#,
+                           NAMESPACE,
+                           xml::escape(name),
+                           xml::escape(prefix)) +
+                   &format!("<KeyCount>{}</KeyCount><MaxKeys>{}</MaxKeys>\
+                             <IsTruncated>{}</IsTruncated>{}{}{}</ListBucketResult>",
+                            end - start,
+                            max_keys,
+                            truncated,
+                            next,
+                            contents,
+                            prefixes)[..])
+    }
+
+    fn object(&mut self,
+              method: &Method,
+              bucket: &str,
+              key: &str,
+              query: &BTreeMap<String, String>,
+              headers: &[(String, String)],
+              body: &[u8])
+              -> Reply {
+        let upload_id = query.get("uploadId").map(|id| &id[..]);
+        match (method.clone(), upload_id) {
+            (Method::Post, None) if query.contains_key("uploads") => {
+                self.initiate(bucket, key, headers)
+            }
+            (Method::Post, Some(id)) => self.complete(bucket, key, id, body),
+            (Method::Put, Some(id)) => {
+                let upload = match self.uploads.get_mut(id) {
+                    Some(upload) => upload,
+                    None => return Reply::no_such_upload(),
+                };
+                if upload.bucket != bucket || upload.key != key {
+                    return Reply::no_such_upload();
+                }
+                match query.get("partNumber").and_then(|n| n.parse::<u32>().ok()) {
+                    Some(n) if n >= 1 => {
+                        let etag = etag(body, None);
+                        upload.parts.insert(n, (etag.clone(), body.to_vec()));
+                        Reply::ok().with_header("etag", &etag[..])
+                    }
+                    _ => Reply::error(400, "InvalidArgument", "Part number must be an integer."),
+                }
+            }
+            (Method::Delete, Some(id)) => {
+                match self.uploads.remove(id) {
+                    Some(_) => Reply::no_content(),
+                    None => Reply::no_such_upload(),
+                }
+            }
+            (Method::Put, None) if query.contains_key("acl") => {
+                let acl = header(headers, "x-amz-acl").unwrap_or("private");
+                if !valid_acl(acl) {
+                    return Reply::error(400, "InvalidArgument", "Unsupported canned ACL.");
+                }
+                match self.buckets.get_mut(bucket).and_then(|objects| objects.get_mut(key)) {
+                    Some(object) => {
+                        object.acl = acl.to_owned();
+                        Reply::ok()
+                    }
+                    None => Reply::no_such_key(),
+                }
+            }
+            (Method::Get, None) if query.contains_key("acl") => {
+                match self.buckets.get(bucket).and_then(|objects| objects.get(key)) {
+                    Some(object) => Reply::xml(acl_policy(&object.acl[..])),
+                    None => Reply::no_such_key(),
+                }
+            }
+            (Method::Put, None) => {
+                let acl = header(headers, "x-amz-acl").unwrap_or("private");
+                if !valid_acl(acl) {
+                    return Reply::error(400, "InvalidArgument", "Unsupported canned ACL.");
+                }
+                let object = MockObject {
+                    data: body.to_vec(),
+                    etag: etag(body, None),
+                    content_type: header(headers, "content-type")
+                                      .unwrap_or("binary/octet-stream")
+                                      .to_owned(),
+                    acl: acl.to_owned(),
+                };
+                let reply = Reply::ok().with_header("etag", &object.etag[..]);
+                self.buckets.get_mut(bucket).unwrap().insert(key.to_owned(), object);
+                reply
+            }
+            (Method::Get, None) => {
+                match self.buckets.get(bucket).and_then(|objects| objects.get(key)) {
+                    Some(object) => {
+                        Reply {
+                            status: 200,
+                            headers: vec![("content-type".to_owned(),
+                                           object.content_type.clone()),
+                                          ("etag".to_owned(), object.etag.clone())],
+                            body: object.data.clone(),
+                        }
+                    }
+                    None => Reply::no_such_key(),
+                }
+            }
+            (Method::Delete, None) => {
+                self.buckets.get_mut(bucket).unwrap().remove(key);
+                Reply::no_content()
+            }
+            _ => method_not_allowed(),
+        }
+    }
+
+    fn initiate(&mut self, bucket: &str, key: &str, headers: &[(String, String)]) -> Reply {
+        let acl = header(headers, "x-amz-acl").unwrap_or("private");
+        if !valid_acl(acl) {
+            return Reply::error(400, "InvalidArgument", "Unsupported canned ACL.");
+        }
+        self.last_id += 1;
+        let id = format!("2~mock-upload-{}", self.last_id);
+        self.uploads.insert(id.clone(),
+                            MockUpload {
+                                bucket: bucket.to_owned(),
+                                key: key.to_owned(),
+                                content_type: header(headers, "content-type")
+                                                  .unwrap_or("binary/octet-stream")
+                                                  .to_owned(),
+                                acl: acl.to_owned(),
+                                parts: BTreeMap::new(),
+                            });
+        Reply::xml(format!(r#"<InitiateMultipartUploadResult xmlns="{}"><Bucket>{}</Bucket>"#,
+                           NAMESPACE,
+                           xml::escape(bucket)) +
+                   &format!("<Key>{}</Key><UploadId>{}</UploadId>\
+                             </InitiateMultipartUploadResult>",
+                            xml::escape(key),
+                            id)[..])
+    }
+
+    fn complete(&mut self, bucket: &str, key: &str, id: &str, body: &[u8]) -> Reply {
+        let body = String::from_utf8_lossy(body).into_owned();
+        let listed: Vec<(u32, String)> =
+            xml::elements(&body[..], "Part")
+                .into_iter()
+                .map(|p| {
+                    (xml::text(p, "PartNumber").and_then(|n| n.parse().ok()).unwrap_or(0),
+                     xml::text(p, "ETag").unwrap_or(String::new()))
+                })
+                .collect();
+        if listed.is_empty() {
+            return Reply::error(400,
+                                "MalformedXML",
+                                "The XML you provided was not well-formed.");
+        }
+        let data = {
+            let upload = match self.uploads.get(id) {
+                Some(upload) if upload.bucket == bucket && upload.key == key => upload,
+                _ => return Reply::no_such_upload(),
+            };
+            let mut data = vec![];
+            for (i, &(number, ref tag)) in listed.iter().enumerate() {
+                if i > 0 && number <= listed[i - 1].0 {
+                    return Reply::error(400,
+                                        "InvalidPartOrder",
+                                        "The list of parts was not in ascending order.");
+                }
+                match upload.parts.get(&number) {
+                    Some(&(ref e, ref part)) if e == tag => {
+                        if i + 1 < listed.len() && part.len() < MIN_PART_SIZE {
+                            return Reply::error(400,
+                                                "EntityTooSmall",
+                                                "Your proposed upload is smaller than the \
+                                                 minimum allowed object size.");
+                        }
+                        data.extend_from_slice(&part[..]);
+                    }
+                    _ => {
+                        return Reply::error(400,
+                                            "InvalidPart",
+                                            "One or more of the specified parts could not be \
+                                             found.")
+                    }
+                }
+            }
+            data
+        };
+        let upload = self.uploads.remove(id).unwrap();
+        let object = MockObject {
+            etag: etag(&data[..], Some(listed.len())),
+            data: data,
+            content_type: upload.content_type,
+            acl: upload.acl,
+        };
+        let reply = Reply::xml(format!(r#"<CompleteMultipartUploadResult xmlns="{}">"#,
+                                       NAMESPACE) +
+                               &format!("<Bucket>{}</Bucket><Key>{}</Key><ETag>{}</ETag>\
+                                         </CompleteMultipartUploadResult>",
+                                        xml::escape(bucket),
+                                        xml::escape(key),
+                                        xml::escape(&object.etag[..]))[..]);
+        match self.buckets.get_mut(bucket) {
+            Some(objects) => {
+                objects.insert(key.to_owned(), object);
+                reply
+            }
+            None => Reply::no_such_bucket(),
+        }
+    }
+}
+
+// Returns an error reply unless the request is presigned or signed for `ACCESS_KEY`, with a
+// payload hash matching the body
+fn authenticate(headers: &[(String, String)],
+                query: &BTreeMap<String, String>,
+                body: &[u8])
+                -> Option<Reply> {
+    const PREFIX: &'static str = "AWS4-HMAC-SHA256 Credential=";
+    let credential = match (query.get("X-Amz-Credential"), query.get("X-Amz-Signature")) {
+        (Some(credential), Some(_)) => &credential[..],
+        (Some(_), None) => return Some(Reply::error(403, "AccessDenied", "Access Denied")),
+        (None, _) => {
+            let auth = header(headers, "authorization").unwrap_or("");
+            if !auth.starts_with(PREFIX) {
+                return Some(Reply::error(403, "AccessDenied", "Access Denied"));
+            }
+            if header(headers, "x-amz-content-sha256") != Some(&payload_hash(body)[..]) {
+                return Some(Reply::error(400,
+                                         "XAmzContentSHA256Mismatch",
+                                         "The provided 'x-amz-content-sha256' header does not \
+                                          match what was computed."));
+            }
+            &auth[PREFIX.len()..]
+        }
+    };
+    if credential.split('/').next() == Some(ACCESS_KEY) {
+        None
+    } else {
+        Some(Reply::error(403,
+                          "InvalidAccessKeyId",
+                          "The AWS Access Key Id you provided does not exist in our records."))
+    }
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers.iter().find(|&&(ref n, _)| n == name).map(|&(_, ref v)| &v[..])
+}
+
+fn valid_acl(acl: &str) -> bool { acl == "private" || acl == "public-read" }
+
+// Single uploads are tagged with (half) their hash, multipart uploads additionally with the
+// number of parts
+fn etag(data: &[u8], parts: Option<usize>) -> String {
+    let hash = payload_hash(data);
+    match parts {
+        Some(n) => format!("\"{}-{}\"", &hash[..32], n),
+        None => format!("\"{}\"", &hash[..32]),
+    }
+}
+
+fn acl_policy(acl: &str) -> String {
+    let owner = format!("<ID>{}</ID><DisplayName>{}</DisplayName>", OWNER_ID, OWNER_ID);
+    let xsi = r#"xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance""#;
+    let public = if acl == "public-read" {
+        format!(r#"<Grant><Grantee {} xsi:type="Group"><URI>{}</URI></Grantee>"#,
+                xsi,
+                ALL_USERS) + "<Permission>READ</Permission></Grant>"
+    } else {
+        String::new()
+    };
+    format!(r#"<AccessControlPolicy xmlns="{}"><Owner>{}</Owner><AccessControlList>"#,
+            NAMESPACE,
+            owner) +
+    &format!(r#"<Grant><Grantee {} xsi:type="CanonicalUser">{}</Grantee>"#,
+             xsi,
+             owner)[..] +
+    "<Permission>FULL_CONTROL</Permission></Grant>" + &public[..] +
+    "</AccessControlList></AccessControlPolicy>"
+}
+
+fn method_not_allowed() -> Reply {
+    Reply::error(405,
+                 "MethodNotAllowed",
+                 "The specified method is not allowed against this resource.")
+}
@@ -0,0 +1,104 @@
+use std::ascii::AsciiExt;
+use std::fmt;
+use std::io::{Read, Write};
+
+use hyper::client;
+use hyper::{self, Url};
+use hyper::method::Method;
+
+use Error;
+
+/// The raw result of an HTTP exchange, as handed back by a `Transport`
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    /// The HTTP status code (i.e. `200`)
+    pub status: u16,
+    /// All response headers as `(name, value)` pairs
+    pub headers: Vec<(String, String)>,
+    /// The unmodified response body
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Returns the value of the first header matching `name` (case insensitive)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|&&(ref n, _)| n.eq_ignore_ascii_case(name))
+            .map(|&(_, ref v)| &v[..])
+    }
+
+    /// Returns `true` if the status code is in the `2xx` range
+    pub fn is_success(&self) -> bool { self.status >= 200 && self.status < 300 }
+
+    /// Returns the body as a `String`, replacing any invalid UTF-8
+    pub fn body_string(&self) -> String { String::from_utf8_lossy(&self.body[..]).into_owned() }
+}
+
+impl fmt::Display for HttpResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "status: {}\n\
+                headers: {}\n\
+                body: {} bytes\n",
+               self.status,
+               self.headers.iter().fold(String::new(), |acc, &(ref n, ref v)| {
+                   acc + &format!(" {}={},", n, v)[..]
+               }),
+               self.body.len())
+    }
+}
+
+/// The HTTP stack used by `DoManager` to talk to DigitalOcean.
+///
+/// `doapi` ships with `HyperTransport` which is used by default, but any type implementing this
+/// trait can be handed to `DoManager::with_transport` instead (i.e. a recording transport, or an
+/// in-memory fake for unit tests).
+pub trait Transport: Send + Sync {
+    /// Sends a single request and returns the raw response. Non-success status codes must be
+    /// returned as `Ok`, only failures to complete the exchange itself are errors.
+    fn send(&self,
+            method: Method,
+            url: &str,
+            headers: &[(String, String)],
+            body: Option<&[u8]>)
+            -> Result<HttpResponse, Error>;
+}
+
+/// The default `Transport`, backed by `hyper`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HyperTransport;
+
+impl Transport for HyperTransport {
+    fn send(&self,
+            method: Method,
+            url: &str,
+            headers: &[(String, String)],
+            body: Option<&[u8]>)
+            -> Result<HttpResponse, Error> {
+        debug!("Sending {} request to {}", method, url);
+        let url = match Url::parse(url) {
+            Ok(url) => url,
+            Err(e) => return Err(Error::Transport(hyper::Error::Uri(e))),
+        };
+        let mut fresh_req = try!(client::Request::new(method, url));
+        for &(ref name, ref value) in headers {
+            fresh_req.headers_mut().set_raw(name.clone(), vec![value.as_bytes().to_vec()]);
+        }
+        let mut streaming_req = try!(fresh_req.start());
+        if let Some(b) = body {
+            try!(streaming_req.write_all(b));
+        }
+        let mut response = try!(streaming_req.send());
+        let mut body = vec![];
+        try!(response.read_to_end(&mut body));
+        Ok(HttpResponse {
+            status: response.status.to_u16(),
+            headers: response.headers
+                             .iter()
+                             .map(|h| (h.name().to_owned(), h.value_string()))
+                             .collect(),
+            body: body,
+        })
+    }
+}